@@ -28,10 +28,13 @@ impl InspectableAttribute {
 
         ident == "label"
             || ident == "collapse"
+            || ident == "collapsed"
             || ident == "default"
             || ident == "ignore"
             || ident == "wrapper"
             || ident == "read_only"
+            || ident == "button"
+            || ident == "tooltip"
     }
 }
 
@@ -68,10 +71,13 @@ fn extract_inspectable_attributes(
 pub struct InspectableAttributes {
     pub collapse: bool,
     pub label: Option<String>,
+    pub tooltip: Option<String>,
     pub default: Option<syn::Expr>,
     pub ignore: bool,
     pub read_only: bool,
     pub wrapper: Option<syn::ExprPath>,
+    /// `#[inspectable(button = "method")]` shows a button calling `self.method()` instead of the field
+    pub button: Option<syn::Ident>,
     pub custom_attributes: Vec<InspectableAttribute>,
 }
 
@@ -96,9 +102,17 @@ impl InspectableAttributes {
         self.label.as_deref().unwrap_or(fallback)
     }
 
+    /// `ui.label(label)`, with the tooltip shown when hovering the label if there is one
+    pub fn label_ui(&self, label: TokenStream) -> TokenStream {
+        match &self.tooltip {
+            Some(tooltip) => quote! { ui.label(#label).on_hover_text(#tooltip); },
+            None => quote! { ui.label(#label); },
+        }
+    }
+
     pub fn decorate_ui(&self, mut ui: TokenStream, collapse_label: &str, i: usize) -> TokenStream {
         if self.collapse {
-            ui = quote! { bevy_inspector_egui::egui::CollapsingHeader::new(#collapse_label).id_source(#i as u64).show(ui, |ui| { #ui }); };
+            ui = quote! { bevy_inspector_egui::egui::CollapsingHeader::new(#collapse_label).id_source(#i as u64).default_open(false).show(ui, |ui| { #ui }); };
         }
         if self.read_only {
             ui = quote! { ui.wrap(|ui| { ui.set_enabled(false); #ui }); };
@@ -111,6 +125,89 @@ impl InspectableAttributes {
     }
 }
 
+/// Attributes on the type itself, e.g. `#[inspectable(variant_label = "label_fn")] enum E {}`
+#[derive(Default)]
+pub struct InspectableContainerAttributes {
+    pub variant_label: Option<syn::ExprPath>,
+    pub radio: bool,
+    /// methods called by the buttons from `#[inspectable(button = "method")]`
+    pub buttons: Vec<syn::Ident>,
+    /// methods whose results are shown read-only after the fields, from `#[inspectable(computed = "method")]`
+    pub computed: Vec<syn::Ident>,
+    /// `#[inspectable(wrapper)]` shows the single field of a newtype struct directly, without a grid
+    pub wrapper: bool,
+}
+
+pub fn inspectable_container_attributes(
+    attrs: &[syn::Attribute],
+) -> InspectableContainerAttributes {
+    let mut all = InspectableContainerAttributes::default();
+
+    for attribute in extract_inspectable_attributes(attrs) {
+        match attribute {
+            #[rustfmt::skip]
+            InspectableAttribute::Assignment(syn::Member::Named(ident), expr) if ident == "variant_label" => {
+                all.variant_label = Some(parse_path(expr, "variant_label"));
+            }
+            InspectableAttribute::Tag(syn::Member::Named(ident)) if ident == "radio" => {
+                all.radio = true;
+            }
+            InspectableAttribute::Tag(syn::Member::Named(ident)) if ident == "wrapper" => {
+                all.wrapper = true;
+            }
+            InspectableAttribute::Assignment(syn::Member::Named(ident), expr)
+                if ident == "button" =>
+            {
+                all.buttons.push(parse_method(expr, "button"));
+            }
+            InspectableAttribute::Assignment(syn::Member::Named(ident), expr)
+                if ident == "computed" =>
+            {
+                all.computed.push(parse_method(expr, "computed"));
+            }
+            InspectableAttribute::Tag(name) | InspectableAttribute::Assignment(name, _) => {
+                match name {
+                    syn::Member::Named(name) => panic!("unknown attribute '{}'", name),
+                    syn::Member::Unnamed(_) => unreachable!(),
+                }
+            }
+        }
+    }
+
+    all
+}
+
+/// accepts both `attr = path::to::function` and `attr = "path::to::function"`
+fn parse_path(expr: syn::Expr, attribute: &str) -> syn::ExprPath {
+    match expr {
+        syn::Expr::Path(path) => path,
+        syn::Expr::Lit(syn::ExprLit {
+            lit: syn::Lit::Str(str),
+            ..
+        }) => str
+            .parse()
+            .unwrap_or_else(|_| panic!("`{}` attribute expected a path to a function", attribute)),
+        _ => panic!("`{}` attribute expected a path to a function", attribute),
+    }
+}
+
+/// accepts both `attr = method` and `attr = "method"`
+fn parse_method(expr: syn::Expr, attribute: &str) -> syn::Ident {
+    let error = format!("`{}` attribute expected the name of a method", attribute);
+    match expr {
+        syn::Expr::Path(path) => path
+            .path
+            .get_ident()
+            .cloned()
+            .unwrap_or_else(|| panic!("{}", error)),
+        syn::Expr::Lit(syn::ExprLit {
+            lit: syn::Lit::Str(str),
+            ..
+        }) => str.parse().unwrap_or_else(|_| panic!("{}", error)),
+        _ => panic!("{}", error),
+    }
+}
+
 pub fn inspectable_attributes(attrs: &[syn::Attribute]) -> InspectableAttributes {
     let mut all = InspectableAttributes::default();
 
@@ -120,7 +217,9 @@ pub fn inspectable_attributes(attrs: &[syn::Attribute]) -> InspectableAttributes
     // builtins
     for builtin_attribute in builtin_attributes {
         match builtin_attribute {
-            InspectableAttribute::Tag(syn::Member::Named(ident)) if ident == "collapse" => {
+            InspectableAttribute::Tag(syn::Member::Named(ident))
+                if ident == "collapse" || ident == "collapsed" =>
+            {
                 all.collapse = true;
             }
             InspectableAttribute::Tag(syn::Member::Named(ident)) if ident == "ignore" => {
@@ -138,6 +237,14 @@ pub fn inspectable_attributes(attrs: &[syn::Attribute]) -> InspectableAttributes
                 };
             }
             #[rustfmt::skip]
+            InspectableAttribute::Assignment(syn::Member::Named(ident), expr) if ident == "tooltip" => {
+                if let syn::Expr::Lit(syn::ExprLit { lit: syn::Lit::Str(str), .. }) = expr {
+                    all.tooltip = Some(str.value());
+                } else {
+                    panic!("tooltip needs to be a string literal");
+                };
+            }
+            #[rustfmt::skip]
             InspectableAttribute::Assignment(syn::Member::Named(ident), expr) if ident == "default" => {
                 all.default = Some(expr);
             }
@@ -149,6 +256,10 @@ pub fn inspectable_attributes(attrs: &[syn::Attribute]) -> InspectableAttributes
                 };
                 all.wrapper = Some(path);
             }
+            #[rustfmt::skip]
+            InspectableAttribute::Assignment(syn::Member::Named(ident), expr) if ident == "button" => {
+                all.button = Some(parse_method(expr, "button"));
+            }
             InspectableAttribute::Tag(name) | InspectableAttribute::Assignment(name, _) => {
                 match name {
                     syn::Member::Named(name) => panic!("unknown attribute '{}'", name),
@@ -4,15 +4,60 @@ use quote::{quote, ToTokens};
 use std::borrow::Cow;
 
 use crate::{
-    attributes::{inspectable_attributes, InspectableAttributes},
+    attributes::{inspectable_attributes, inspectable_container_attributes, InspectableAttributes},
     utils,
 };
 
 pub fn expand_enum(derive_input: &syn::DeriveInput, data: &syn::DataEnum) -> TokenStream {
     let name = &derive_input.ident;
+    let container_attributes = inspectable_container_attributes(&derive_input.attrs);
+    if !container_attributes.buttons.is_empty() {
+        panic!("#[inspectable(button = <method>)] is only for structs");
+    }
+    if !container_attributes.computed.is_empty() {
+        panic!("#[inspectable(computed = <method>)] is only for structs");
+    }
+    if container_attributes.wrapper {
+        panic!("#[inspectable(wrapper)] is only for structs");
+    }
 
     let variant_names: Vec<_> = data.variants.iter().map(|variant| &variant.ident).collect();
 
+    // the text displayed for a variant, `variant_label(stringify!(Variant))` if specified
+    let label = |variant: TokenStream| match &container_attributes.variant_label {
+        Some(variant_label) => quote! { #variant_label(#variant) },
+        None => variant,
+    };
+    let selected_label = label(quote! { variant });
+    let variant_labels = variant_names
+        .iter()
+        .map(|variant| label(quote! { stringify!(#variant) }));
+
+    // the variants are selected using either radio buttons or a combobox
+    let variant_selection = if container_attributes.radio {
+        quote! {
+            ui.horizontal_wrapped(|ui| {
+                #(if ui.radio(matches!(self, #name::#variant_names { .. }), #variant_labels).clicked() {
+                    variant = stringify!(#variant_names);
+                    changed = true;
+                })*
+            });
+        }
+    } else {
+        quote! {
+            ui.horizontal(|ui| {
+                bevy_inspector_egui::egui::ComboBox::from_id_source(context.id())
+                    .selected_text(#selected_label)
+                    .show_ui(ui, |ui| {
+                        #(if ui.selectable_label(matches!(self, #name::#variant_names { .. }), #variant_labels).clicked() {
+                            variant = stringify!(#variant_names);
+                            changed = true;
+                        })*
+                    });
+            });
+        }
+    };
+
     // used to check whether the combobox and the fields below should be `ui.group`ed,
     // which is the case if the variant contains any fields.
     let should_group_arms = data.variants.iter().map(|variant| {
@@ -70,6 +115,12 @@ pub fn expand_enum(derive_input: &syn::DeriveInput, data: &syn::DataEnum) -> Tok
 
 
             fn ui(&mut self, ui: &mut #egui::Ui, options: Self::Attributes, context: &bevy_inspector_egui::Context) -> bool {
+                if context.max_depth_reached() {
+                    ui.label("(max depth reached)");
+                    return false;
+                }
+                let context = &context.nested();
+
                 let mut variant = match self {
                     #(Self::#variant_names { .. } => stringify!(#variant_names),)*
                 };
@@ -85,16 +136,7 @@ pub fn expand_enum(derive_input: &syn::DeriveInput, data: &syn::DataEnum) -> Tok
 
                 group_if(ui, should_group, |ui| {
                     ui.vertical(|ui| {
-                        ui.horizontal(|ui| {
-                            bevy_inspector_egui::egui::ComboBox::from_id_source(context.id())
-                                .selected_text(variant)
-                                .show_ui(ui, |ui| {
-                                    #(if ui.selectable_label(matches!(self, #name::#variant_names { .. }), stringify!(#variant_names)).clicked() {
-                                        variant = stringify!(#variant_names);
-                                        changed = true;
-                                    })*
-                                });
-                        });
+                        #variant_selection
 
                         match variant {
                             #(#ui_match_arms)*
@@ -157,6 +199,9 @@ fn field_ui(
         if attributes.ignore {
             return quote! {};
         }
+        if attributes.button.is_some() {
+            panic!("#[inspectable(button = <method>)] is only for structs");
+        }
 
         let ui = if f.len() == 1 {
             quote! {
@@ -164,9 +209,10 @@ fn field_ui(
                 changed |= #binding_name.ui(ui, options, context);
             }
         } else {
+            let label_ui = attributes.label_ui(quote! { stringify!(#member) });
             quote! {
                 ui.horizontal(|ui| {
-                    ui.label(stringify!(#member));
+                    #label_ui
                     let options = #options;
                     changed |= #binding_name.ui(ui, options, context);
                 });
@@ -1,11 +1,25 @@
 use proc_macro2::TokenStream;
 use quote::quote;
 
-use crate::utils;
+use crate::{attributes::InspectableContainerAttributes, utils};
 
 pub fn expand_struct(derive_input: &syn::DeriveInput, data: &syn::DataStruct) -> TokenStream {
     let name = &derive_input.ident;
 
+    let container_attributes =
+        crate::attributes::inspectable_container_attributes(&derive_input.attrs);
+    if container_attributes.variant_label.is_some() {
+        panic!("#[inspectable(variant_label = <fn>)] is only for enums");
+    }
+    if container_attributes.radio {
+        panic!("#[inspectable(radio)] is only for enums");
+    }
+
+    if container_attributes.wrapper {
+        return expand_wrapper(derive_input, data, &container_attributes);
+    }
+
+    let ids = utils::field_ids(&data.fields);
     let fields: Vec<_> = data
         .fields
         .iter()
@@ -16,7 +30,7 @@ pub fn expand_struct(derive_input: &syn::DeriveInput, data: &syn::DataStruct) ->
         .collect();
 
     let field_setup = fields.iter().map(|(field, attributes)| {
-        if attributes.ignore {
+        if attributes.ignore || attributes.button.is_some() {
             return quote! {};
         }
 
@@ -33,6 +47,7 @@ pub fn expand_struct(derive_input: &syn::DeriveInput, data: &syn::DataStruct) ->
         }
 
         let ty = &field.ty;
+        let id = ids[i];
 
         let accessor = utils::field_accessor(field, i);
         let field_label = utils::field_label(field, i);
@@ -42,22 +57,61 @@ pub fn expand_struct(derive_input: &syn::DeriveInput, data: &syn::DataStruct) ->
             panic!("#[inspectable(default = <expr>)] is only for enums");
         }
 
+        let label_ui = attributes.label_ui(quote! { #field_label });
+        let field_label_ui =
+            attributes.label_ui(quote! { context.with_id(#id as u64).mixed_label(#field_label) });
+
+        if let Some(method) = &attributes.button {
+            let text = method.to_string();
+            return quote! {
+                #label_ui
+                if ui.button(#text).clicked() {
+                    self.#method();
+                    changed = true;
+                }
+                ui.end_row();
+            };
+        }
+
         // user specified options
         let options = attributes.create_options_struct(ty);
 
         let ui = quote! {
             let options = #options;
-            changed |= <#ty as bevy_inspector_egui::Inspectable>::ui(&mut self.#accessor, ui, options, &context.with_id(#i as u64));
+            changed |= <#ty as bevy_inspector_egui::Inspectable>::ui(&mut self.#accessor, ui, options, &context.with_id(#id as u64));
         };
-        let ui = attributes.decorate_ui(ui, field_label, i);
+        let ui = attributes.decorate_ui(ui, field_label, id);
 
         quote! {
-            ui.label(#field_label);
+            #field_label_ui
             #ui
             ui.end_row();
         }
     });
 
+    let computed = container_attributes.computed.iter().map(|method| {
+        let text = method.to_string();
+        quote! {
+            ui.label(#text);
+            ui.label(self.#method().to_string());
+            ui.end_row();
+        }
+    });
+
+    let buttons = container_attributes.buttons.iter().map(|method| {
+        let text = method.to_string();
+        quote! {
+            if ui.button(#text).clicked() {
+                self.#method();
+                changed = true;
+            }
+        }
+    });
+    let buttons = match container_attributes.buttons.is_empty() {
+        true => quote! {},
+        false => quote! { ui.horizontal(|ui| { #(#buttons)* }); },
+    };
+
     quote! {
         #[allow(clippy::all)]
         impl bevy_inspector_egui::Inspectable for #name {
@@ -67,12 +121,20 @@ pub fn expand_struct(derive_input: &syn::DeriveInput, data: &syn::DataStruct) ->
             fn ui(&mut self, ui: &mut bevy_inspector_egui::egui::Ui, options: Self::Attributes, context: &bevy_inspector_egui::Context) -> bool {
                 use bevy_inspector_egui::egui;
 
+                if context.max_depth_reached() {
+                    ui.label("(max depth reached)");
+                    return false;
+                }
+                let context = &context.nested();
+
                 let mut changed = false;
                 ui.vertical_centered(|ui| {
                     let grid = egui::Grid::new(context.id());
                     grid.show(ui, |ui| {
                         #(#fields)*
+                        #(#computed)*
                     });
+                    #buttons
                 });
                 changed
             }
@@ -83,3 +145,59 @@ pub fn expand_struct(derive_input: &syn::DeriveInput, data: &syn::DataStruct) ->
         }
     }
 }
+
+/// `#[inspectable(wrapper)]`: the ui of the only field, without a grid or label around it.
+fn expand_wrapper(
+    derive_input: &syn::DeriveInput,
+    data: &syn::DataStruct,
+    container_attributes: &InspectableContainerAttributes,
+) -> TokenStream {
+    let name = &derive_input.ident;
+
+    if data.fields.len() != 1 {
+        panic!("#[inspectable(wrapper)] is only for structs with a single field");
+    }
+    if !container_attributes.buttons.is_empty() || !container_attributes.computed.is_empty() {
+        panic!("#[inspectable(wrapper)] can't be combined with `button` or `computed`");
+    }
+
+    let field = data.fields.iter().next().unwrap();
+    let attributes = crate::attributes::inspectable_attributes(&field.attrs);
+    if attributes.ignore || attributes.button.is_some() {
+        panic!("the field of a #[inspectable(wrapper)] struct can't be ignored or a button");
+    }
+    if attributes.default.is_some() {
+        panic!("#[inspectable(default = <expr>)] is only for enums");
+    }
+
+    let ty = &field.ty;
+    let accessor = utils::field_accessor(field, 0);
+    // e.g. the header text for `collapse`
+    let struct_label = name.to_string();
+    let label = attributes.label(&struct_label);
+    let options = attributes.create_options_struct(ty);
+
+    let ui = quote! {
+        let options = #options;
+        changed |= <#ty as bevy_inspector_egui::Inspectable>::ui(&mut self.#accessor, ui, options, context);
+    };
+    let ui = attributes.decorate_ui(ui, label, 0);
+
+    quote! {
+        #[allow(clippy::all)]
+        impl bevy_inspector_egui::Inspectable for #name {
+            type Attributes = ();
+
+
+            fn ui(&mut self, ui: &mut bevy_inspector_egui::egui::Ui, options: Self::Attributes, context: &bevy_inspector_egui::Context) -> bool {
+                let mut changed = false;
+                #ui
+                changed
+            }
+
+            fn setup(app: &mut bevy::prelude::AppBuilder) {
+                <#ty as bevy_inspector_egui::Inspectable>::setup(app);
+            }
+        }
+    }
+}
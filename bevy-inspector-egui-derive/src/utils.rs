@@ -11,3 +11,37 @@ pub fn field_label(field: &syn::Field, i: usize) -> String {
         None => i.to_string(),
     }
 }
+
+/// The context ids of the fields, which match their index in the derived `Reflect` implementation.
+/// That one skips fields with `#[reflect(ignore)]`, so they are numbered after all other fields.
+pub fn field_ids(fields: &syn::Fields) -> Vec<usize> {
+    let reflected = fields
+        .iter()
+        .filter(|field| !is_reflect_ignored(field))
+        .count();
+    let (mut next_reflected, mut next_ignored) = (0, reflected);
+    fields
+        .iter()
+        .map(|field| {
+            let next = match is_reflect_ignored(field) {
+                true => &mut next_ignored,
+                false => &mut next_reflected,
+            };
+            *next += 1;
+            *next - 1
+        })
+        .collect()
+}
+
+fn is_reflect_ignored(field: &syn::Field) -> bool {
+    field.attrs.iter().any(|attr| {
+        let nested = match attr.parse_meta() {
+            Ok(syn::Meta::List(list)) if list.path.is_ident("reflect") => list.nested,
+            _ => return false,
+        };
+        let ignored = nested.iter().any(|meta| {
+            matches!(meta, syn::NestedMeta::Meta(syn::Meta::Path(path)) if path.is_ident("ignore"))
+        });
+        ignored
+    })
+}
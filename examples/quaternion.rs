@@ -3,7 +3,7 @@ use bevy_inspector_egui::{options::QuatDisplay, Inspectable, InspectorPlugin};
 
 #[derive(Inspectable, Default)]
 struct Data {
-    #[inspectable(display = QuatDisplay::Euler)]
+    #[inspectable(display = QuatDisplay::Euler, gizmo)]
     euler: Quat,
     #[inspectable(display = QuatDisplay::YawPitchRoll)]
     ypr: Quat,
@@ -0,0 +1,84 @@
+use bevy::{app::PluginGroupBuilder, pbr::AmbientLight, prelude::*};
+use bevy_egui::egui;
+
+use crate::{
+    Context, DisabledSystems, Inspectable, InspectorPlugin, RunConditions, WorldInspectorPlugin,
+};
+
+/// Plugin group adding the [`WorldInspectorPlugin`] and a window for editing
+/// common resources, namely [`ClearColor`], [`AmbientLight`], [`Msaa`], [`Windows`], [`Time`],
+/// [`DisabledSystems`], [`RunConditions`], the load states of the [`AssetServer`]
+/// and the gamepad state in [`Input<GamepadButton>`] and [`Axis<GamepadAxis>`].
+///
+/// Resources which don't exist in the world are left out.
+/// ```rust,no_run
+/// use bevy::prelude::*;
+/// use bevy_inspector_egui::DefaultInspectorPlugins;
+///
+/// fn main() {
+///     App::build()
+///         .add_plugins(DefaultPlugins)
+///         .add_plugins(DefaultInspectorPlugins)
+///         .run();
+/// }
+/// ```
+#[derive(Debug, Default)]
+pub struct DefaultInspectorPlugins;
+
+impl PluginGroup for DefaultInspectorPlugins {
+    fn build(&mut self, group: &mut PluginGroupBuilder) {
+        group.add(WorldInspectorPlugin::new());
+        group.add(InspectorPlugin::<Resources>::new());
+    }
+}
+
+/// Displays the common resources. The name is used as the window title.
+#[derive(Default)]
+struct Resources;
+
+impl Inspectable for Resources {
+    type Attributes = ();
+
+    fn ui(&mut self, ui: &mut egui::Ui, _: Self::Attributes, context: &Context) -> bool {
+        let world = expect_world!(ui, context, "Resources");
+
+        let mut changed = false;
+        egui::Grid::new(context.id()).show(ui, |ui| {
+            changed |= resource_ui::<ClearColor>(ui, world, "clear color", &context.with_id(0));
+            changed |= resource_ui::<AmbientLight>(ui, world, "ambient light", &context.with_id(1));
+            changed |= resource_ui::<Msaa>(ui, world, "msaa", &context.with_id(2));
+            changed |= resource_ui::<Windows>(ui, world, "windows", &context.with_id(3));
+            changed |= resource_ui::<Time>(ui, world, "time", &context.with_id(4));
+            changed |= resource_ui::<DisabledSystems>(ui, world, "systems", &context.with_id(5));
+            changed |=
+                resource_ui::<RunConditions>(ui, world, "run conditions", &context.with_id(6));
+            changed |= resource_ui::<Input<GamepadButton>>(
+                ui,
+                world,
+                "gamepad buttons",
+                &context.with_id(7),
+            );
+            changed |=
+                resource_ui::<Axis<GamepadAxis>>(ui, world, "gamepad axes", &context.with_id(8));
+            changed |= resource_ui::<AssetServer>(ui, world, "assets", &context.with_id(9));
+        });
+        changed
+    }
+}
+
+fn resource_ui<T: Inspectable + Send + Sync + 'static>(
+    ui: &mut egui::Ui,
+    world: &mut World,
+    label: &str,
+    context: &Context,
+) -> bool {
+    let mut resource = match world.get_resource_mut::<T>() {
+        Some(resource) => resource,
+        None => return false,
+    };
+
+    ui.label(label);
+    let changed = resource.ui(ui, Default::default(), context);
+    ui.end_row();
+    changed
+}
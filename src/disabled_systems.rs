@@ -0,0 +1,104 @@
+use std::{
+    collections::BTreeSet,
+    sync::{Arc, Mutex},
+};
+
+use bevy::{
+    ecs::{schedule::ShouldRun, system::System},
+    prelude::*,
+    utils::HashSet,
+};
+use bevy_egui::egui;
+
+use crate::{Context, Inspectable};
+
+/// Resource listing the systems gated by [`inspector_gated`] which are currently disabled.
+///
+/// When inspected, every gated system which ran at least once is listed with a checkbox for turning it on and off.
+/// ```rust,no_run
+/// use bevy::prelude::*;
+/// use bevy_inspector_egui::{inspector_gated, DisabledSystems, InspectorPlugin};
+///
+/// fn main() {
+///     App::build()
+///         .add_plugins(DefaultPlugins)
+///         .add_plugin(InspectorPlugin::<DisabledSystems>::new())
+///         .add_system(movement.system().with_run_criteria(inspector_gated("movement")))
+///         .run();
+/// }
+/// # fn movement() {}
+/// ```
+#[derive(Debug, Default)]
+pub struct DisabledSystems {
+    /// The names of the disabled systems, as passed to [`inspector_gated`]
+    pub disabled: HashSet<&'static str>,
+    /// Shared so that the run criteria can list its system without mutable access to the resource
+    gated: Arc<Mutex<BTreeSet<&'static str>>>,
+}
+
+impl DisabledSystems {
+    /// Whether the system gated by `inspector_gated(name)` is disabled
+    pub fn is_disabled(&self, name: &str) -> bool {
+        self.disabled.contains(name)
+    }
+
+    /// Stop running the system gated by `inspector_gated(name)`
+    pub fn disable(&mut self, name: &'static str) {
+        self.disabled.insert(name);
+    }
+
+    /// Resume running the system gated by `inspector_gated(name)`
+    pub fn enable(&mut self, name: &str) {
+        self.disabled.remove(name);
+    }
+}
+
+/// Run criteria which skips the system while `name` is disabled in the [`DisabledSystems`] resource.
+/// Systems run normally if the resource doesn't exist.
+pub fn inspector_gated(name: &'static str) -> impl System<In = (), Out = ShouldRun> {
+    (move |mut registered: Local<bool>, disabled_systems: Option<Res<DisabledSystems>>| {
+        let disabled_systems = match disabled_systems {
+            Some(disabled_systems) => disabled_systems,
+            None => return ShouldRun::Yes,
+        };
+        if !*registered {
+            disabled_systems.gated.lock().unwrap().insert(name);
+            *registered = true;
+        }
+
+        match disabled_systems.is_disabled(name) {
+            true => ShouldRun::No,
+            false => ShouldRun::Yes,
+        }
+    })
+    .system()
+}
+
+impl Inspectable for DisabledSystems {
+    type Attributes = ();
+
+    fn ui(&mut self, ui: &mut egui::Ui, _: Self::Attributes, context: &Context) -> bool {
+        let gated = self.gated.lock().unwrap().clone();
+        if gated.is_empty() {
+            ui.label("No gated systems have run yet");
+            return false;
+        }
+
+        let mut changed = false;
+        egui::Grid::new(context.id()).show(ui, |ui| {
+            for &name in &gated {
+                ui.label(name);
+                let mut enabled = !self.disabled.contains(name);
+                if ui.checkbox(&mut enabled, "").changed() {
+                    match enabled {
+                        true => self.disabled.remove(name),
+                        false => self.disabled.insert(name),
+                    };
+                    changed = true;
+                }
+                ui.end_row();
+            }
+        });
+        changed
+    }
+}
@@ -0,0 +1,38 @@
+use bevy::{
+    asset::HandleId,
+    audio::{Audio, AudioSource},
+    prelude::*,
+};
+use bevy_egui::egui;
+
+use super::with_context::asset_picker;
+use crate::{Context, Inspectable};
+
+/// Picks the audio asset and shows a button for playing it through the [`Audio`] resource.
+impl Inspectable for Handle<AudioSource> {
+    type Attributes = ();
+
+    fn ui(&mut self, ui: &mut egui::Ui, _: Self::Attributes, context: &Context) -> bool {
+        let world = expect_world!(ui, context, "Handle<AudioSource>");
+        let changed = asset_picker(ui, self, world, context);
+
+        if self.id == HandleId::default::<AudioSource>() {
+            return changed;
+        }
+
+        let assets = world.get_resource::<Assets<AudioSource>>().unwrap();
+        let len = assets.get(self.clone()).map(|source| source.bytes.len());
+        ui.horizontal(|ui| {
+            let audio = world.get_resource::<Audio>();
+            let play = egui::Button::new("▶ Play").enabled(len.is_some() && audio.is_some());
+            if ui.add(play).clicked() {
+                audio.unwrap().play(self.clone());
+            }
+            match len {
+                Some(len) => ui.label(format!("{} bytes", len)),
+                None => ui.label("(not loaded)"),
+            };
+        });
+        changed
+    }
+}
@@ -1,5 +1,6 @@
+use super::quat::to_euler_angles;
 use crate::options::{NumberAttributes, OptionAttributes, Vec2dAttributes};
-use crate::{Context, Inspectable};
+use crate::{Context, Inspectable, InspectableRegistry};
 use bevy::{
     asset::HandleId,
     render::{
@@ -8,18 +9,14 @@ use bevy::{
         pipeline::PrimitiveTopology,
     },
 };
-use bevy::{pbr::AmbientLight, prelude::*};
+use bevy::{
+    ecs::component::Component, log::warn, pbr::AmbientLight, prelude::*, sprite::SpriteResizeMode,
+    utils::HashSet, window::WindowMode,
+};
 use bevy_egui::egui;
 use egui::Grid;
+use std::{fmt::Debug, hash::Hash};
 
-impl_for_struct_delegate_fields!(
-    Light:
-    color,
-    fov,
-    depth with NumberAttributes::positive().speed(1.0),
-    intensity with NumberAttributes::positive().speed(1.0),
-    range with NumberAttributes::positive(),
-);
 impl_for_struct_delegate_fields!(ColorMaterial: color, texture);
 impl_for_simple_enum!(
     PrimitiveTopology: PointList,
@@ -93,6 +90,12 @@ impl Inspectable for shape::Box {
 
 //////// COMPONENTS ////////
 
+/// The ids of the transforms whose translation is edited along their local axes.
+#[derive(Default)]
+struct LocalSpaceTransforms(HashSet<egui::Id>);
+
+/// The translation can be edited along the axes of the parent (`World`) or along the entity's own
+/// rotated axes (`Local`). Switching between them requires access to the world.
 impl Inspectable for Transform {
     type Attributes = ();
 
@@ -102,19 +105,47 @@ impl Inspectable for Transform {
         _options: Self::Attributes,
         context: &Context,
     ) -> bool {
+        let id = ui.id().with(context.id());
+        let world = unsafe { context.world() };
+        let was_local = match &world {
+            Some(world) => {
+                matches!(world.get_resource::<LocalSpaceTransforms>(), Some(local) if local.0.contains(&id))
+            }
+            None => false,
+        };
+        let mut local = was_local;
+
         let mut changed = false;
         ui.vertical_centered(|ui| {
             Grid::new(context.id()).show(ui, |ui| {
-                ui.label("Translation");
-                changed |= self.translation.ui(ui, Default::default(), context);
+                if world.is_some() {
+                    ui.label("Space");
+                    ui.horizontal(|ui| {
+                        ui.selectable_value(&mut local, false, "World");
+                        ui.selectable_value(&mut local, true, "Local");
+                    });
+                    ui.end_row();
+                }
+
+                ui.label(context.with_id(0).mixed_label("Translation"));
+                match local {
+                    true => {
+                        let mut translation = self.rotation.inverse() * self.translation;
+                        if translation.ui(ui, Default::default(), context) {
+                            self.translation = self.rotation * translation;
+                            changed = true;
+                        }
+                    }
+                    false => changed |= self.translation.ui(ui, Default::default(), context),
+                }
                 ui.end_row();
 
-                ui.label("Rotation");
+                ui.label(context.with_id(1).mixed_label("Rotation"));
                 changed |= self.rotation.ui(ui, Default::default(), context);
                 self.rotation = self.rotation.normalize();
                 ui.end_row();
 
-                ui.label("Scale");
+                ui.label(context.with_id(2).mixed_label("Scale"));
                 let scale_attributes = NumberAttributes {
                     min: Some(Vec3::splat(0.0)),
                     ..Default::default()
@@ -123,14 +154,49 @@ impl Inspectable for Transform {
                 ui.end_row();
             });
         });
+
+        if let (true, Some(world)) = (local != was_local, world) {
+            let mut local_transforms =
+                world.get_resource_or_insert_with(LocalSpaceTransforms::default);
+            match local {
+                true => local_transforms.0.insert(id),
+                false => local_transforms.0.remove(&id),
+            };
+        }
+
         changed
     }
 }
 
+#[derive(Default, Debug, Clone)]
+pub struct GlobalTransformAttributes {
+    /// Edit the global transform like a `Transform` instead of only displaying it.
+    /// The global transform usually gets overwritten by transform propagation anyway.
+    pub editable: bool,
+}
+
 impl Inspectable for GlobalTransform {
-    type Attributes = <Transform as Inspectable>::Attributes;
+    type Attributes = GlobalTransformAttributes;
 
     fn ui(&mut self, ui: &mut egui::Ui, options: Self::Attributes, context: &Context) -> bool {
+        if !options.editable {
+            let degrees = to_euler_angles(self.rotation) * (180.0 / std::f32::consts::PI);
+            Grid::new(context.id()).show(ui, |ui| {
+                ui.label("Translation");
+                ui.label(format_vec3(self.translation));
+                ui.end_row();
+
+                ui.label("Rotation");
+                ui.label(format!("{}°", format_vec3(degrees)));
+                ui.end_row();
+
+                ui.label("Scale");
+                ui.label(format_vec3(self.scale));
+                ui.end_row();
+            });
+            return false;
+        }
+
         let global_transform = std::mem::take(self);
 
         let mut transform = Transform {
@@ -139,7 +205,7 @@ impl Inspectable for GlobalTransform {
             scale: global_transform.scale,
         };
 
-        let changed = transform.ui(ui, options, context);
+        let changed = transform.ui(ui, (), context);
 
         *self = GlobalTransform {
             translation: transform.translation,
@@ -151,6 +217,61 @@ impl Inspectable for GlobalTransform {
     }
 }
 
+fn format_vec3(vec: Vec3) -> String {
+    format!("{:.3}, {:.3}, {:.3}", vec.x, vec.y, vec.z)
+}
+
+impl Inspectable for Light {
+    type Attributes = ();
+
+    fn ui(&mut self, ui: &mut egui::Ui, _: Self::Attributes, context: &Context) -> bool {
+        let mut changed = false;
+        ui.vertical_centered(|ui| {
+            Grid::new(context.id()).show(ui, |ui| {
+                ui.label("color");
+                changed |= self.color.ui(ui, Default::default(), &context.with_id(0));
+                ui.end_row();
+
+                ui.label("fov");
+                let mut fov = self.fov.to_degrees();
+                let fov_attributes = NumberAttributes {
+                    suffix: "°".to_string(),
+                    ..NumberAttributes::between(0.0, 180.0).speed(1.0)
+                };
+                if fov.ui(ui, fov_attributes, &context.with_id(1)) {
+                    self.fov = fov.to_radians();
+                    changed = true;
+                }
+                ui.end_row();
+
+                ui.label("depth");
+                let depth_attributes = NumberAttributes::positive().speed(1.0);
+                changed |= self.depth.ui(ui, depth_attributes, &context.with_id(2));
+                ui.end_row();
+
+                ui.label("intensity");
+                let intensity_attributes = NumberAttributes {
+                    suffix: " lm".to_string(),
+                    ..NumberAttributes::between(0.0, 10_000.0).speed(1.0)
+                };
+                changed |= self
+                    .intensity
+                    .ui(ui, intensity_attributes, &context.with_id(3));
+                ui.end_row();
+
+                ui.label("range");
+                let range_attributes = NumberAttributes {
+                    suffix: " units".to_string(),
+                    ..NumberAttributes::positive().speed(0.1)
+                };
+                changed |= self.range.ui(ui, range_attributes, &context.with_id(4));
+                ui.end_row();
+            });
+        });
+        changed
+    }
+}
+
 impl Inspectable for Mat3 {
     type Attributes = ();
 
@@ -214,6 +335,21 @@ impl Inspectable for Color {
     }
 }
 
+impl Inspectable for Visible {
+    type Attributes = ();
+
+    fn ui(&mut self, ui: &mut egui::Ui, _: Self::Attributes, _: &Context) -> bool {
+        ui.horizontal(|ui| {
+            let visible = ui.checkbox(&mut self.is_visible, "visible").changed();
+            let transparent = ui
+                .checkbox(&mut self.is_transparent, "transparent")
+                .changed();
+            visible || transparent
+        })
+        .inner
+    }
+}
+
 //////// RESOURCES ////////
 
 impl Inspectable for AmbientLight {
@@ -222,10 +358,13 @@ impl Inspectable for AmbientLight {
     fn ui(&mut self, ui: &mut egui::Ui, options: Self::Attributes, context: &Context) -> bool {
         let brightness_attributes = NumberAttributes::positive().speed(0.01);
 
-        self.color.ui(ui, options, context);
-        self.brightness.ui(ui, brightness_attributes, context)
+        let mut changed = self.color.ui(ui, options, context);
+        changed |= self.brightness.ui(ui, brightness_attributes, context);
+        changed
     }
 }
+/// Edited with the color picker. The alpha channel is kept as is, unless `alpha` is set in the attributes,
+/// since it only has an effect on transparent windows.
 impl Inspectable for ClearColor {
     type Attributes = <Color as Inspectable>::Attributes;
 
@@ -233,6 +372,207 @@ impl Inspectable for ClearColor {
         self.0.ui(ui, options, context)
     }
 }
+impl Inspectable for Msaa {
+    type Attributes = ();
+
+    fn ui(&mut self, ui: &mut egui::Ui, _: Self::Attributes, context: &Context) -> bool {
+        const SAMPLE_COUNTS: [u32; 4] = [1, 2, 4, 8];
+
+        let mut changed = false;
+        egui::ComboBox::from_id_source(context.id())
+            .selected_text(format!("{} samples", self.samples))
+            .show_ui(ui, |ui| {
+                for &samples in SAMPLE_COUNTS.iter() {
+                    let is_current = samples == self.samples;
+                    if ui
+                        .selectable_label(is_current, format!("{} samples", samples))
+                        .clicked()
+                        && !is_current
+                    {
+                        self.samples = samples;
+                        changed = true;
+                    }
+                }
+            });
+        changed
+    }
+}
+
+impl Inspectable for Windows {
+    type Attributes = ();
+
+    fn ui(&mut self, ui: &mut egui::Ui, _: Self::Attributes, context: &Context) -> bool {
+        let mut changed = false;
+        ui.vertical(|ui| {
+            for (i, window) in self.iter_mut().enumerate() {
+                egui::CollapsingHeader::new(window.title().to_string())
+                    .id_source(context.id().with(window.id()))
+                    .default_open(true)
+                    .show(ui, |ui| {
+                        changed |= window.ui(ui, (), &context.with_id(i as u64));
+                    });
+            }
+        });
+        changed
+    }
+}
+
+/// Changes to the window are applied through its setters, which queue the commands for the windowing backend.
+impl Inspectable for Window {
+    type Attributes = ();
+
+    fn ui(&mut self, ui: &mut egui::Ui, _: Self::Attributes, context: &Context) -> bool {
+        let mut changed = false;
+        Grid::new(context.id()).show(ui, |ui| {
+            ui.label("id");
+            match self.id().is_primary() {
+                true => ui.label("primary"),
+                false => ui.label(self.id().to_string()),
+            };
+            ui.end_row();
+
+            ui.label("title");
+            let mut title = self.title().to_string();
+            if title.ui(ui, Default::default(), &context.with_id(0)) {
+                self.set_title(title);
+                changed = true;
+            }
+            ui.end_row();
+
+            ui.label("resolution");
+            let mut resolution = Vec2::new(self.requested_width(), self.requested_height());
+            let attributes = Vec2dAttributes {
+                min: Some(Vec2::ONE),
+                ..Vec2dAttributes::integer()
+            };
+            if resolution.ui(ui, attributes, &context.with_id(1)) {
+                self.set_resolution(resolution.x, resolution.y);
+                changed = true;
+            }
+            ui.end_row();
+
+            ui.label("scale factor");
+            ui.label(format!("{:.2}", self.scale_factor()));
+            ui.end_row();
+
+            ui.label("mode");
+            let mut mode = self.mode();
+            if window_mode_ui(&mut mode, ui, &context.with_id(2)) {
+                self.set_mode(mode);
+                changed = true;
+            }
+            ui.end_row();
+
+            let mut checkbox = |ui: &mut egui::Ui, label, value: bool| -> Option<bool> {
+                ui.label(label);
+                let mut value = value;
+                let clicked = ui.checkbox(&mut value, "").changed();
+                ui.end_row();
+                changed |= clicked;
+                match clicked {
+                    true => Some(value),
+                    false => None,
+                }
+            };
+            if let Some(vsync) = checkbox(ui, "vsync", self.vsync()) {
+                self.set_vsync(vsync);
+            }
+            if let Some(resizable) = checkbox(ui, "resizable", self.resizable()) {
+                self.set_resizable(resizable);
+            }
+            if let Some(decorations) = checkbox(ui, "decorations", self.decorations()) {
+                self.set_decorations(decorations);
+            }
+            if let Some(visible) = checkbox(ui, "cursor visible", self.cursor_visible()) {
+                self.set_cursor_visibility(visible);
+            }
+            if let Some(locked) = checkbox(ui, "cursor locked", self.cursor_locked()) {
+                self.set_cursor_lock_mode(locked);
+            }
+        });
+        changed
+    }
+}
+
+/// Exponential moving average of the frames per second displayed for [`Time`].
+#[derive(Default)]
+struct SmoothedFps(Option<f64>);
+
+impl SmoothedFps {
+    const SMOOTHING: f64 = 0.1;
+}
+
+impl Inspectable for Time {
+    type Attributes = ();
+
+    fn ui(&mut self, ui: &mut egui::Ui, _: Self::Attributes, context: &Context) -> bool {
+        let delta = self.delta_seconds_f64();
+        let fps = match delta > 0.0 {
+            true => Some(1.0 / delta),
+            false => None,
+        };
+        let fps = match unsafe { context.world() } {
+            Some(world) => {
+                let mut smoothed = world.get_resource_or_insert_with(SmoothedFps::default);
+                smoothed.0 = match (smoothed.0, fps) {
+                    (Some(smoothed), Some(fps)) => {
+                        Some(smoothed + (fps - smoothed) * SmoothedFps::SMOOTHING)
+                    }
+                    (smoothed, fps) => fps.or(smoothed),
+                };
+                smoothed.0
+            }
+            None => fps,
+        };
+
+        Grid::new(context.id()).show(ui, |ui| {
+            ui.label("delta");
+            ui.label(format!("{:.2} ms", delta * 1000.0));
+            ui.end_row();
+
+            ui.label("elapsed");
+            ui.label(format!("{:.2} s", self.seconds_since_startup()));
+            ui.end_row();
+
+            ui.label("fps");
+            match fps {
+                Some(fps) => ui.label(format!("{:.1}", fps)),
+                None => ui.label("n/a"),
+            };
+            ui.end_row();
+        });
+        false
+    }
+}
+
+fn window_mode_ui(mode: &mut WindowMode, ui: &mut egui::Ui, context: &Context) -> bool {
+    let label = |mode: &WindowMode| match mode {
+        WindowMode::Windowed => "Windowed",
+        WindowMode::BorderlessFullscreen => "Borderless fullscreen",
+        WindowMode::Fullscreen { use_size: false } => "Fullscreen",
+        WindowMode::Fullscreen { use_size: true } => "Fullscreen (window size)",
+    };
+    let modes = [
+        WindowMode::Windowed,
+        WindowMode::BorderlessFullscreen,
+        WindowMode::Fullscreen { use_size: false },
+        WindowMode::Fullscreen { use_size: true },
+    ];
+
+    let mut changed = false;
+    egui::ComboBox::from_id_source(context.id())
+        .selected_text(label(mode))
+        .show_ui(ui, |ui| {
+            for candidate in modes.iter() {
+                let is_current = candidate == mode;
+                if ui.selectable_label(is_current, label(candidate)).clicked() && !is_current {
+                    *mode = *candidate;
+                    changed = true;
+                }
+            }
+        });
+    changed
+}
 
 ////// OTHER //////
 
@@ -241,6 +581,13 @@ impl_for_struct_delegate_fields!(bevy::sprite::Rect:
     max with Vec2dAttributes::integer(),
 );
 impl_for_struct_delegate_fields!(TextureAtlasSprite: color, index, flip_x, flip_y);
+impl_for_struct_delegate_fields!(Sprite:
+    size with Vec2dAttributes { min: Some(Vec2::ZERO), ..Vec2dAttributes::integer() },
+    flip_x,
+    flip_y,
+    resize_mode,
+);
+impl_for_simple_enum!(SpriteResizeMode: Manual, Automatic);
 
 impl Inspectable for TextureAtlas {
     type Attributes = ();
@@ -341,7 +688,7 @@ impl Inspectable for Mesh {
     fn ui(&mut self, ui: &mut egui::Ui, _: Self::Attributes, context: &Context) -> bool {
         Grid::new(context.id()).show(ui, |ui| {
             ui.label("Primitive Topology");
-            let _ = ui.button(format!("{:?}", self.primitive_topology()));
+            ui.label(format!("{:?}", self.primitive_topology()));
 
             let attributes = &[
                 Mesh::ATTRIBUTE_POSITION,
@@ -356,15 +703,18 @@ impl Inspectable for Mesh {
             ui.label(self.count_vertices().to_string());
             ui.end_row();
 
-            if let Some(indices) = self.indices() {
-                ui.label("Indices");
-                let len = match indices {
-                    Indices::U16(vec) => vec.len(),
-                    Indices::U32(vec) => vec.len(),
-                };
-                ui.label(len.to_string());
-                ui.end_row();
-            }
+            ui.label("Indices");
+            match self.indices() {
+                Some(indices) => {
+                    let len = match indices {
+                        Indices::U16(vec) => vec.len(),
+                        Indices::U32(vec) => vec.len(),
+                    };
+                    ui.label(len.to_string())
+                }
+                None => ui.label("none"),
+            };
+            ui.end_row();
 
             ui.label("Vertex Attributes");
             ui.collapsing("Attributes", |ui| {
@@ -405,6 +755,232 @@ impl Inspectable for VisibleEntities {
     }
 }
 
+/// Read-only view of which buttons are pressed, just pressed or just released.
+impl<T> Inspectable for Input<T>
+where
+    T: Copy + Eq + Hash + Debug + Send + Sync + 'static,
+{
+    type Attributes = ();
+
+    fn ui(&mut self, ui: &mut egui::Ui, _: Self::Attributes, context: &Context) -> bool {
+        Grid::new(context.id()).show(ui, |ui| {
+            ui.label("Pressed");
+            buttons_ui(ui, self.get_pressed(), egui::Color32::from_rgb(0, 130, 0));
+            ui.end_row();
+
+            ui.label("Just pressed");
+            buttons_ui(
+                ui,
+                self.get_just_pressed(),
+                egui::Color32::from_rgb(0, 140, 180),
+            );
+            ui.end_row();
+
+            ui.label("Just released");
+            buttons_ui(
+                ui,
+                self.get_just_released(),
+                egui::Color32::from_rgb(160, 0, 0),
+            );
+            ui.end_row();
+        });
+        false
+    }
+}
+
+fn buttons_ui<'a, T: Debug + 'a>(
+    ui: &mut egui::Ui,
+    buttons: impl Iterator<Item = &'a T>,
+    color: egui::Color32,
+) {
+    let mut buttons: Vec<String> = buttons.map(|button| format!("{:?}", button)).collect();
+    buttons.sort();
+
+    ui.horizontal_wrapped(|ui| {
+        if buttons.is_empty() {
+            ui.label("-");
+        }
+        for button in buttons {
+            ui.add(
+                egui::Label::new(button)
+                    .background_color(color)
+                    .text_color(egui::Color32::WHITE),
+            );
+        }
+    });
+}
+
+/// The gamepads which are looked at, as bevy doesn't keep a list of the connected ones.
+const MAX_GAMEPADS: usize = 8;
+
+const GAMEPAD_AXIS_TYPES: [GamepadAxisType; 8] = [
+    GamepadAxisType::LeftStickX,
+    GamepadAxisType::LeftStickY,
+    GamepadAxisType::LeftZ,
+    GamepadAxisType::RightStickX,
+    GamepadAxisType::RightStickY,
+    GamepadAxisType::RightZ,
+    GamepadAxisType::DPadX,
+    GamepadAxisType::DPadY,
+];
+
+const GAMEPAD_BUTTON_TYPES: [GamepadButtonType; 19] = [
+    GamepadButtonType::South,
+    GamepadButtonType::East,
+    GamepadButtonType::North,
+    GamepadButtonType::West,
+    GamepadButtonType::C,
+    GamepadButtonType::Z,
+    GamepadButtonType::LeftTrigger,
+    GamepadButtonType::LeftTrigger2,
+    GamepadButtonType::RightTrigger,
+    GamepadButtonType::RightTrigger2,
+    GamepadButtonType::Select,
+    GamepadButtonType::Start,
+    GamepadButtonType::Mode,
+    GamepadButtonType::LeftThumb,
+    GamepadButtonType::RightThumb,
+    GamepadButtonType::DPadUp,
+    GamepadButtonType::DPadDown,
+    GamepadButtonType::DPadLeft,
+    GamepadButtonType::DPadRight,
+];
+
+/// Read-only view of the stick and trigger positions of the connected gamepads.
+impl Inspectable for Axis<GamepadAxis> {
+    type Attributes = ();
+
+    fn ui(&mut self, ui: &mut egui::Ui, _: Self::Attributes, context: &Context) -> bool {
+        gamepad_axes_ui(ui, context, -1.0, |gamepad| {
+            GAMEPAD_AXIS_TYPES
+                .iter()
+                .filter_map(|&axis_type| {
+                    let value = self.get(GamepadAxis(gamepad, axis_type))?;
+                    Some((format!("{:?}", axis_type), value))
+                })
+                .collect()
+        });
+        false
+    }
+}
+
+/// Read-only view of how far the buttons of the connected gamepads are pressed.
+impl Inspectable for Axis<GamepadButton> {
+    type Attributes = ();
+
+    fn ui(&mut self, ui: &mut egui::Ui, _: Self::Attributes, context: &Context) -> bool {
+        gamepad_axes_ui(ui, context, 0.0, |gamepad| {
+            GAMEPAD_BUTTON_TYPES
+                .iter()
+                .filter_map(|&button_type| {
+                    let value = self.get(GamepadButton(gamepad, button_type))?;
+                    Some((format!("{:?}", button_type), value))
+                })
+                .collect()
+        });
+        false
+    }
+}
+
+/// Shows a bar for every `(name, value)` returned by `values` for each gamepad.
+/// Values range from `min` to `1.0`.
+fn gamepad_axes_ui(
+    ui: &mut egui::Ui,
+    context: &Context,
+    min: f32,
+    values: impl Fn(Gamepad) -> Vec<(String, f32)>,
+) {
+    let gamepads: Vec<_> = (0..MAX_GAMEPADS)
+        .map(|id| (id, values(Gamepad(id))))
+        .filter(|(_, values)| !values.is_empty())
+        .collect();
+    if gamepads.is_empty() {
+        ui.label("No gamepads connected");
+        return;
+    }
+
+    ui.vertical(|ui| {
+        for (id, values) in gamepads {
+            ui.label(format!("Gamepad {}", id));
+            Grid::new(context.id().with(id)).show(ui, |ui| {
+                for (name, value) in values {
+                    ui.label(name);
+                    axis_bar(ui, value, min);
+                    ui.label(format!("{:.2}", value));
+                    ui.end_row();
+                }
+            });
+        }
+    });
+}
+
+/// Draws a bar filled from `0.0` to `value`, in a range from `min` to `1.0`.
+fn axis_bar(ui: &mut egui::Ui, value: f32, min: f32) {
+    let size = egui::vec2(100.0, ui.spacing().interact_size.y * 0.6);
+    let (rect, _) = ui.allocate_exact_size(size, egui::Sense::hover());
+
+    let visuals = &ui.visuals().widgets.inactive;
+    let x = |value: f32| {
+        let t = (value.clamp(min, 1.0) - min) / (1.0 - min);
+        rect.left() + t * rect.width()
+    };
+    let (from, to) = (x(0.0), x(value));
+    let filled = egui::Rect::from_x_y_ranges(from.min(to)..=from.max(to), rect.y_range());
+
+    let painter = ui.painter();
+    painter.rect_filled(rect, 2.0, visuals.bg_fill);
+    painter.rect_filled(filled, 2.0, egui::Color32::from_rgb(0, 140, 180));
+    painter.rect_stroke(rect, 2.0, visuals.bg_stroke);
+}
+
+impl<T> Inspectable for State<T>
+where
+    T: Component + Debug + Clone + Eq + Hash,
+{
+    type Attributes = ();
+
+    fn ui(&mut self, ui: &mut egui::Ui, _: Self::Attributes, context: &Context) -> bool {
+        let current = format!("{:?}", self.current());
+
+        let registry = unsafe { context.world() }
+            .and_then(|world| world.get_resource::<InspectableRegistry>());
+        let variants = match registry.and_then(|registry| registry.state_variants::<T>()) {
+            Some(variants) => variants,
+            None => {
+                ui.label(current);
+                return false;
+            }
+        };
+
+        let mut selected = None;
+        egui::ComboBox::from_id_source(context.id())
+            .selected_text(current)
+            .show_ui(ui, |ui| {
+                for variant in variants {
+                    let is_current = variant == self.current();
+                    if ui
+                        .selectable_label(is_current, format!("{:?}", variant))
+                        .clicked()
+                        && !is_current
+                    {
+                        selected = Some(variant.clone());
+                    }
+                }
+            });
+
+        match selected {
+            Some(state) => match self.set(state) {
+                Ok(()) => true,
+                Err(e) => {
+                    warn!("failed to set state: {:?}", e);
+                    false
+                }
+            },
+            None => false,
+        }
+    }
+}
+
 impl<'a, T: Inspectable> Inspectable for Mut<'a, T> {
     type Attributes = T::Attributes;
 
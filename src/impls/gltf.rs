@@ -0,0 +1,49 @@
+use std::collections::HashMap;
+
+use bevy::gltf::Gltf;
+use bevy_egui::egui;
+
+use crate::{Context, Inspectable};
+
+/// Read-only list of the named nodes, meshes, scenes and materials of the glTF file,
+/// for finding out which parts of it an entity was spawned from.
+impl Inspectable for Gltf {
+    type Attributes = ();
+
+    fn ui(&mut self, ui: &mut egui::Ui, _: Self::Attributes, context: &Context) -> bool {
+        egui::Grid::new(context.id()).show(ui, |ui| {
+            names_ui(ui, "nodes", &self.named_nodes, self.nodes.len());
+            names_ui(ui, "meshes", &self.named_meshes, self.meshes.len());
+            names_ui(ui, "scenes", &self.named_scenes, self.scenes.len());
+            names_ui(ui, "materials", &self.named_materials, self.materials.len());
+        });
+        false
+    }
+}
+
+/// A grid row listing the sorted names, followed by the number of unnamed entries.
+fn names_ui<T>(ui: &mut egui::Ui, label: &str, named: &HashMap<String, T>, total: usize) {
+    ui.label(label);
+    ui.vertical(|ui| {
+        if total == 0 {
+            ui.label("None");
+        }
+
+        let mut names: Vec<&String> = named.keys().collect();
+        names.sort();
+        for name in names {
+            ui.label(name);
+        }
+
+        match total.saturating_sub(named.len()) {
+            0 => {}
+            1 => {
+                ui.label("(1 unnamed)");
+            }
+            unnamed => {
+                ui.label(format!("({} unnamed)", unnamed));
+            }
+        }
+    });
+    ui.end_row();
+}
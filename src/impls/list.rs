@@ -1,18 +1,25 @@
-use bevy::prelude::AppBuilder;
+use std::{any::Any, hash::Hash};
+
+use bevy::{
+    log::warn,
+    prelude::AppBuilder,
+    utils::{HashMap, HashSet},
+};
 
 use crate::{egui, Context};
 use crate::{utils, Inspectable};
 
 impl<T> Inspectable for Vec<T>
 where
-    T: Inspectable + Default,
+    T: Inspectable + Default + 'static,
 {
     type Attributes = <T as Inspectable>::Attributes;
 
     fn ui(&mut self, ui: &mut egui::Ui, options: Self::Attributes, context: &Context) -> bool {
         let mut changed = false;
 
-        ui.vertical(|ui| {
+        let preview = list_preview(self.iter(), self.len());
+        collection_header(ui, "items", self.len(), preview, context, |ui, context| {
             let mut to_delete = None;
 
             let len = self.len();
@@ -50,12 +57,178 @@ where
     }
 }
 
-impl<T: Inspectable, const N: usize> Inspectable for [T; N] {
+impl<T> Inspectable for HashSet<T>
+where
+    T: Inspectable + Eq + Hash + Clone + Default + Send + Sync + 'static,
+{
     type Attributes = <T as Inspectable>::Attributes;
 
+    fn ui(&mut self, ui: &mut egui::Ui, options: Self::Attributes, context: &Context) -> bool {
+        // elements are edited out-of-place, because changing them would invalidate their hash.
+        // rebuilding the set reorders the elements, which would move the focus to another element,
+        // so the edited copy is kept until nothing is focused or dragged anymore.
+        let id = ui.id().with(context.id());
+        let pending = take_edited_elements::<T>(context, id);
+        let was_pending = pending.is_some();
+        let mut elements = pending.unwrap_or_else(|| self.iter().cloned().collect());
+
+        let mut edited = false;
+        let preview = list_preview(elements.iter(), elements.len());
+        collection_header(
+            ui,
+            "items",
+            elements.len(),
+            preview,
+            context,
+            |ui, context| {
+                let mut to_delete = None;
+
+                let len = elements.len();
+                for (i, val) in elements.iter_mut().enumerate() {
+                    ui.horizontal(|ui| {
+                        if utils::ui::label_button(ui, "✖", egui::Color32::RED) {
+                            to_delete = Some(i);
+                        }
+                        edited |= val.ui(ui, options.clone(), &context.with_id(i as u64));
+                    });
+
+                    if i != len - 1 {
+                        ui.separator();
+                    }
+                }
+
+                ui.vertical_centered_justified(|ui| {
+                    if ui.button("+").clicked() {
+                        let value = T::default();
+                        if !elements.contains(&value) {
+                            elements.push(value);
+                            edited = true;
+                        }
+                    }
+                });
+
+                if let Some(i) = to_delete {
+                    elements.remove(i);
+                    edited = true;
+                }
+            },
+        );
+
+        if !edited && !was_pending {
+            return false;
+        }
+
+        let interacting =
+            ui.ctx().wants_keyboard_input() || ui.memory().is_anything_being_dragged();
+        let elements = match interacting {
+            true => match keep_edited_elements(context, id, elements) {
+                Some(elements) => elements,
+                None => return false,
+            },
+            false => elements,
+        };
+
+        let len = elements.len();
+        self.clear();
+        self.extend(elements);
+        if self.len() != len {
+            warn!(
+                "dropped {} duplicate element(s) from `HashSet<{}>`",
+                len - self.len(),
+                std::any::type_name::<T>()
+            );
+        }
+        true
+    }
+
+    fn setup(app: &mut AppBuilder) {
+        T::setup(app);
+    }
+}
+
+/// The elements of the sets which are being edited, keyed by their ui and context id.
+#[derive(Default)]
+struct EditedElements(HashMap<egui::Id, Box<dyn Any + Send + Sync>>);
+
+fn take_edited_elements<T: Send + Sync + 'static>(
+    context: &Context,
+    id: egui::Id,
+) -> Option<Vec<T>> {
+    let world = unsafe { context.world() }?;
+    let mut edited = world.get_resource_mut::<EditedElements>()?;
+    let elements = edited.0.remove(&id)?;
+    elements.downcast().ok().map(|elements| *elements)
+}
+
+/// Keeps the edited `elements` until the next frame.
+/// Without access to the world there is nowhere to keep them, so they are given back to be applied immediately.
+fn keep_edited_elements<T: Send + Sync + 'static>(
+    context: &Context,
+    id: egui::Id,
+    elements: Vec<T>,
+) -> Option<Vec<T>> {
+    let world = match unsafe { context.world() } {
+        Some(world) => world,
+        None => return Some(elements),
+    };
+    let mut edited = world.get_resource_or_insert_with(EditedElements::default);
+    edited.0.insert(id, Box::new(elements));
+    None
+}
+
+impl<K, V> Inspectable for HashMap<K, V>
+where
+    K: Inspectable + Clone + 'static,
+    V: Inspectable + 'static,
+{
+    type Attributes = <V as Inspectable>::Attributes;
+
     fn ui(&mut self, ui: &mut egui::Ui, options: Self::Attributes, context: &Context) -> bool {
         let mut changed = false;
-        ui.vertical(|ui| {
+
+        let preview = map_preview(self.iter(), self.len());
+        collection_header(
+            ui,
+            "entries",
+            self.len(),
+            preview,
+            context,
+            |ui, context| {
+                let len = self.len();
+                for (i, (key, value)) in self.iter_mut().enumerate() {
+                    let context = context.with_id(i as u64);
+                    ui.horizontal(|ui| {
+                        // keys are read-only, because changing them would invalidate their hash
+                        ui.horizontal(|ui| {
+                            ui.set_enabled(false);
+                            key.clone().ui(ui, Default::default(), &context.with_id(0));
+                        });
+                        changed |= value.ui(ui, options.clone(), &context.with_id(1));
+                    });
+
+                    if i != len - 1 {
+                        ui.separator();
+                    }
+                }
+            },
+        );
+
+        changed
+    }
+
+    fn setup(app: &mut AppBuilder) {
+        K::setup(app);
+        V::setup(app);
+    }
+}
+
+impl<T: Inspectable + 'static, const N: usize> Inspectable for [T; N] {
+    type Attributes = <T as Inspectable>::Attributes;
+
+    fn ui(&mut self, ui: &mut egui::Ui, options: Self::Attributes, context: &Context) -> bool {
+        let mut changed = false;
+        let preview = list_preview(self.iter(), N);
+        collection_header(ui, "items", N, preview, context, |ui, context| {
             for (i, val) in self.iter_mut().enumerate() {
                 ui.horizontal(|ui| {
                     ui.label(i.to_string());
@@ -71,6 +244,123 @@ impl<T: Inspectable, const N: usize> Inspectable for [T; N] {
     }
 }
 
+/// Collections with at most this many elements are shown inline, without a header.
+const INLINE_LEN: usize = 4;
+/// How many elements the preview of a collapsed collection shows.
+const PREVIEW_LEN: usize = 3;
+/// How many characters the preview of a collapsed collection may have.
+const PREVIEW_MAX_CHARS: usize = 40;
+
+/// Shows the elements of a collection.
+/// The number of elements is shown above the collection. Collections with more than [`INLINE_LEN`] elements
+/// show it on a header instead, so that they can be collapsed, and the `preview` is shown on the collapsed header.
+/// The elements are passed a nested context, unless the maximum depth is reached.
+fn collection_header(
+    ui: &mut egui::Ui,
+    elements: &str,
+    len: usize,
+    preview: Option<String>,
+    context: &Context,
+    add_contents: impl FnOnce(&mut egui::Ui, &Context),
+) {
+    if context.max_depth_reached() {
+        ui.label("(max depth reached)");
+        return;
+    }
+
+    let title = format!("{} ({})", elements, len);
+    if len <= INLINE_LEN {
+        ui.vertical(|ui| {
+            ui.label(title);
+            add_contents(ui, &context.nested());
+        });
+        return;
+    }
+
+    let response = egui::CollapsingHeader::new(title)
+        .id_source(context.id())
+        .default_open(true)
+        .show(ui, |ui| add_contents(ui, &context.nested()));
+
+    if let (None, Some(preview)) = (response.body_returned, preview) {
+        let rect = response.header_response.rect;
+        ui.painter().text(
+            egui::pos2(
+                rect.right() - ui.spacing().button_padding.x,
+                rect.center().y,
+            ),
+            egui::Align2::RIGHT_CENTER,
+            preview,
+            egui::TextStyle::Button,
+            ui.visuals().weak_text_color(),
+        );
+    }
+}
+
+/// `[a, b, c, …]` for collections of primitive values.
+fn list_preview<'a, T: 'static>(
+    elements: impl Iterator<Item = &'a T>,
+    len: usize,
+) -> Option<String> {
+    let elements = elements
+        .take(PREVIEW_LEN)
+        .map(preview_value)
+        .collect::<Option<Vec<_>>>()?;
+    Some(format!("[{}]", preview_elements(elements, len)))
+}
+
+/// `{a: 1, b: 2, c: 3, …}` for maps of primitive keys and values.
+fn map_preview<'a, K: 'static, V: 'static>(
+    entries: impl Iterator<Item = (&'a K, &'a V)>,
+    len: usize,
+) -> Option<String> {
+    let entries = entries
+        .take(PREVIEW_LEN)
+        .map(|(key, value)| {
+            Some(format!(
+                "{}: {}",
+                preview_value(key)?,
+                preview_value(value)?
+            ))
+        })
+        .collect::<Option<Vec<_>>>()?;
+    Some(format!("{{{}}}", preview_elements(entries, len)))
+}
+
+fn preview_elements(elements: Vec<String>, len: usize) -> String {
+    let mut preview = elements.join(", ");
+    if len > elements.len() {
+        preview.push_str(", …");
+    }
+    if preview.chars().count() > PREVIEW_MAX_CHARS {
+        preview = preview.chars().take(PREVIEW_MAX_CHARS - 1).collect();
+        preview.push('…');
+    }
+    preview
+}
+
+/// The value as text, if it is a primitive.
+fn preview_value<T: 'static>(value: &T) -> Option<String> {
+    let value = value as &dyn Any;
+    macro_rules! display {
+        ($($ty:ty),*) => {
+            $(
+                if let Some(value) = value.downcast_ref::<$ty>() {
+                    return Some(value.to_string());
+                }
+            )*
+        };
+    }
+    display!(i8, i16, i32, i64, isize, u8, u16, u32, u64, usize, f32, f64, bool, char);
+    if let Some(value) = value.downcast_ref::<String>() {
+        return Some(format!("{:?}", value));
+    }
+    if let Some(value) = value.downcast_ref::<&'static str>() {
+        return Some(format!("{:?}", value));
+    }
+    None
+}
+
 macro_rules! impl_for_tuple {
     ( $($ty:ident : $i:tt),* ) => {
         #[allow(unused_variables, non_snake_case)]
@@ -78,6 +368,12 @@ macro_rules! impl_for_tuple {
             type Attributes = ($(<$ty as Inspectable>::Attributes,)*);
 
             fn ui(&mut self, ui: &mut egui::Ui, options: Self::Attributes, context: &Context) -> bool {
+                if context.max_depth_reached() {
+                    ui.label("(max depth reached)");
+                    return false;
+                }
+                let context = &context.nested();
+
                 #[allow(unused_mut)]
                 let mut inline = true;
                 $(inline &= should_display_inline::<$ty>();)*
@@ -1,13 +1,14 @@
 mod bevy_impls;
 mod list;
 mod number;
+mod pointers;
 mod primitives;
 mod quat;
 mod ui;
 mod vec;
 pub(crate) mod with_context;
 
-pub use bevy_impls::ColorAttributes;
+pub use bevy_impls::{ColorAttributes, GlobalTransformAttributes};
 pub use number::NumberAttributes;
 pub use primitives::{OptionAttributes, StringAttributes};
 pub use quat::{QuatAttributes, QuatDisplay};
@@ -16,3 +17,9 @@ pub use with_context::{FilterType, TextureAttributes};
 
 #[cfg(any(feature = "rapier", feature = "rapier2d"))]
 mod rapier;
+
+#[cfg(feature = "gltf")]
+mod gltf;
+
+#[cfg(feature = "audio")]
+mod audio;
@@ -1,4 +1,10 @@
+use std::{collections::VecDeque, fmt, num::ParseIntError, str::FromStr};
+
+use bevy::{log::warn, utils::HashMap};
+
+use super::primitives::parsed_text_ui;
 use crate::egui::{self, widgets};
+use crate::utils::ui::with_copy_button;
 use crate::Context;
 use crate::Inspectable;
 
@@ -10,6 +16,19 @@ pub struct NumberAttributes<T> {
     pub speed: f32,
     pub prefix: String,
     pub suffix: String,
+    /// Whether the value is an angle in radians, which should be edited in degrees.
+    /// `min`, `max` and `speed` are then in degrees as well.
+    pub angle: bool,
+    /// Whether the value is edited as hexadecimal text like `0x2A`. Only for unsigned integers.
+    pub hex: bool,
+    /// Whether the value is edited as an ASCII character. Only for unsigned integers like `u8`.
+    pub ascii: bool,
+    /// Whether the value is a direction, which shows a button for normalizing it. Only for `Vec3` and `Vec3A`.
+    pub direction: bool,
+    /// Whether a direction is normalized whenever it is edited.
+    pub normalize_on_edit: bool,
+    /// Whether to plot the recent values next to the number. Requires access to the world.
+    pub graph: bool,
 }
 impl<T> Default for NumberAttributes<T> {
     fn default() -> Self {
@@ -19,6 +38,12 @@ impl<T> Default for NumberAttributes<T> {
             speed: 0.0,
             prefix: "".to_string(),
             suffix: "".to_string(),
+            angle: false,
+            hex: false,
+            ascii: false,
+            direction: false,
+            normalize_on_edit: false,
+            graph: false,
         }
     }
 }
@@ -31,6 +56,12 @@ impl<T> NumberAttributes<T> {
             speed: self.speed,
             prefix: self.prefix.clone(),
             suffix: self.suffix.clone(),
+            angle: self.angle,
+            hex: self.hex,
+            ascii: self.ascii,
+            direction: self.direction,
+            normalize_on_edit: self.normalize_on_edit,
+            graph: self.graph,
         }
     }
 
@@ -64,10 +95,20 @@ impl NumberAttributes<f32> {
     }
 }
 
-pub trait Num: emath::Numeric {
+pub trait Num: emath::Numeric + std::fmt::Display {
     fn default_speed() -> Option<f32> {
         None
     }
+
+    /// The value as an unsigned integer, without going through `f64`. Only for unsigned integers.
+    fn to_u64(self) -> Option<u64> {
+        None
+    }
+
+    /// The unsigned integer as this type, if it fits. Only for unsigned integers.
+    fn from_u64(_: u64) -> Option<Self> {
+        None
+    }
 }
 
 impl Num for f32 {
@@ -81,61 +122,345 @@ impl Num for f64 {
     }
 }
 impl Num for i8 {}
-impl Num for u8 {}
 impl Num for i16 {}
-impl Num for u16 {}
 impl Num for i32 {}
-impl Num for u32 {}
 impl Num for i64 {}
-impl Num for u64 {}
 impl Num for isize {}
-impl Num for usize {}
+
+macro_rules! impl_num_unsigned {
+    ($($ty:ty),*) => {
+        $(impl Num for $ty {
+            fn to_u64(self) -> Option<u64> {
+                Some(self as u64)
+            }
+
+            fn from_u64(value: u64) -> Option<Self> {
+                std::convert::TryFrom::try_from(value).ok()
+            }
+        })*
+    };
+}
+impl_num_unsigned!(u8, u16, u32, u64, usize);
+
+/// The number which is currently being dragged when using `Context::commit_on_release`,
+/// identified by its ui and context id.
+#[derive(Default)]
+struct DraggedNumber(Option<(egui::Id, f64)>);
 
 impl<T: Num> Inspectable for T {
     type Attributes = NumberAttributes<T>;
 
-    fn ui(&mut self, ui: &mut egui::Ui, options: Self::Attributes, _: &Context) -> bool {
-        let mut widget = widgets::DragValue::new(self);
-
-        if !options.prefix.is_empty() {
-            widget = widget.prefix(options.prefix);
-        }
-        if !options.suffix.is_empty() {
-            widget = widget.suffix(options.suffix);
+    fn ui(&mut self, ui: &mut egui::Ui, options: Self::Attributes, context: &Context) -> bool {
+        if !options.graph {
+            return with_copy_button(ui, context, self, |ui, value| {
+                number_ui(value, ui, options, context)
+            });
         }
 
-        match (options.min, options.max) {
-            (Some(min), Some(max)) => widget = widget.clamp_range(min.to_f64()..=max.to_f64()),
-            (Some(min), None) => widget = widget.clamp_range(min.to_f64()..=f64::MAX),
-            (None, Some(max)) => widget = widget.clamp_range(f64::MIN..=max.to_f64()),
-            (None, None) => {}
+        ui.horizontal(|ui| {
+            let changed = with_copy_button(ui, context, self, |ui, value| {
+                number_ui(value, ui, options, context)
+            });
+            graph_ui(self.to_f64(), ui, context);
+            changed
+        })
+        .inner
+    }
+}
+
+/// The recent values of the numbers with `#[inspectable(graph)]`, identified by their ui and context id,
+/// together with the time they were last recorded at.
+/// Numbers which haven't been shown for [`NumberHistory::EVICT_AFTER`] seconds are forgotten.
+#[derive(Default)]
+struct NumberHistory {
+    values: HashMap<egui::Id, (f64, VecDeque<f64>)>,
+    evicted_at: f64,
+}
+
+impl NumberHistory {
+    const LEN: usize = 100;
+    const EVICT_AFTER: f64 = 1.0;
+
+    /// Forgets the numbers which weren't recorded recently, at most once per frame.
+    fn evict(&mut self, time: f64) {
+        if self.evicted_at == time {
+            return;
         }
+        self.evicted_at = time;
+        self.values
+            .retain(|_, (recorded_at, _)| time - *recorded_at < Self::EVICT_AFTER);
+    }
+}
 
-        if options.speed != 0.0 {
-            widget = widget.speed(options.speed);
-        } else if let Some(default_speed) = T::default_speed() {
-            widget = widget.speed(default_speed);
+/// Records `value` once per frame and plots the recent values as a line.
+fn graph_ui(value: f64, ui: &mut egui::Ui, context: &Context) {
+    const SIZE: egui::Vec2 = egui::vec2(60.0, 16.0);
+
+    let world = match unsafe { context.world() } {
+        Some(world) => world,
+        None => return,
+    };
+    let mut history = world.get_resource_or_insert_with(NumberHistory::default);
+
+    let time = ui.input().time;
+    history.evict(time);
+    let id = ui.id().with(context.id());
+    let (recorded_at, values) = history.values.entry(id).or_default();
+    if *recorded_at != time || values.is_empty() {
+        if values.len() == NumberHistory::LEN {
+            values.pop_front();
         }
+        values.push_back(value);
+        *recorded_at = time;
+    }
 
-        let mut changed = ui.add(widget).changed();
+    let (rect, response) = ui.allocate_exact_size(SIZE, egui::Sense::hover());
+    let visuals = ui.style().visuals.widgets.inactive;
+    ui.painter().rect_filled(rect, 2.0, visuals.bg_fill);
+
+    let min = values.iter().copied().fold(f64::INFINITY, f64::min);
+    let max = values.iter().copied().fold(f64::NEG_INFINITY, f64::max);
+    let range = max - min;
+    let points = values
+        .iter()
+        .enumerate()
+        .map(|(i, &value)| {
+            let x = rect.left() + rect.width() * i as f32 / (NumberHistory::LEN - 1) as f32;
+            let y = match range > 0.0 {
+                true => rect.bottom() - rect.height() * ((value - min) / range) as f32,
+                false => rect.center().y,
+            };
+            egui::pos2(x, y)
+        })
+        .collect();
+    ui.painter()
+        .add(egui::Shape::line(points, visuals.fg_stroke));
+
+    response.on_hover_text(format!("min {:.3}, max {:.3}", min, max));
+}
+
+fn number_ui<T: Num>(
+    number: &mut T,
+    ui: &mut egui::Ui,
+    options: NumberAttributes<T>,
+    context: &Context,
+) -> bool {
+    if options.angle {
+        return angle_ui(number, ui, options, context);
+    }
+    if let (true, Some(current)) = (options.hex || options.ascii, number.to_u64()) {
+        return text_number_ui(number, current, ui, options, context);
+    }
+
+    let world = match unsafe { context.world() } {
+        Some(world) if context.commit_on_release() => world,
+        _ => return drag_value(number, ui, options).0,
+    };
+    let mut dragged = world.get_resource_or_insert_with(DraggedNumber::default);
+
+    let id = ui.id().with(context.id());
+    let scratch = dragged
+        .0
+        .filter(|&(dragged_id, _)| dragged_id == id)
+        .map(|(_, value)| value);
+
+    let mut value = scratch.map(T::from_f64).unwrap_or(*number);
+    let (changed, response) = drag_value(&mut value, ui, options);
+
+    if response.dragged() {
+        dragged.0 = Some((id, value.to_f64()));
+        return false;
+    }
+
+    if scratch.is_some() {
+        dragged.0 = None;
+    }
+
+    let changed = changed || (scratch.is_some() && value != *number);
+    *number = value;
+    changed
+}
 
-        if let Some(min) = options.min {
-            let as_f64 = self.to_f64();
-            let min = min.to_f64();
-            if as_f64 < min {
-                *self = T::from_f64(min);
-                changed = true;
+/// Edits an angle in radians as degrees.
+fn angle_ui<T: Num>(
+    number: &mut T,
+    ui: &mut egui::Ui,
+    options: NumberAttributes<T>,
+    context: &Context,
+) -> bool {
+    let mut options = options.map(|value| value.to_f64());
+    options.angle = false;
+    if options.suffix.is_empty() {
+        options.suffix = "°".to_string();
+    }
+    if options.speed == 0.0 {
+        options.speed = 1.0;
+    }
+
+    let mut degrees = number.to_f64().to_degrees();
+    let changed = number_ui(&mut degrees, ui, options, context);
+    if changed {
+        *number = T::from_f64(degrees.to_radians());
+    }
+    changed
+}
+
+/// Unsigned integer written as `0x2A`, for [`NumberAttributes::hex`].
+#[derive(PartialEq)]
+struct Hex(u64);
+
+impl fmt::Display for Hex {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:#04X}", self.0)
+    }
+}
+
+impl FromStr for Hex {
+    type Err = ParseIntError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        let digits = s
+            .strip_prefix("0x")
+            .or_else(|| s.strip_prefix("0X"))
+            .unwrap_or(s);
+        u64::from_str_radix(digits, 16).map(Hex)
+    }
+}
+
+/// Character which can be stored in a single byte, for [`NumberAttributes::ascii`].
+#[derive(PartialEq)]
+struct Ascii(char);
+
+impl fmt::Display for Ascii {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl FromStr for Ascii {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.parse::<char>() {
+            Ok(c) if c.is_ascii() => Ok(Ascii(c)),
+            _ => Err(()),
+        }
+    }
+}
+
+/// Edits an unsigned integer, whose value is `current`, as hexadecimal text or as an ASCII character.
+/// Values which aren't ASCII characters are edited as numbers instead.
+fn text_number_ui<T: Num>(
+    number: &mut T,
+    current: u64,
+    ui: &mut egui::Ui,
+    options: NumberAttributes<T>,
+    context: &Context,
+) -> bool {
+    let value = match options.ascii {
+        true => {
+            let c = match std::char::from_u32(current as u32) {
+                Some(c) if current <= 0x7F => c,
+                _ => {
+                    return ui
+                        .horizontal(|ui| {
+                            let changed = drag_value(number, ui, options).0;
+                            ui.label("(not ASCII)");
+                            changed
+                        })
+                        .inner
+                }
+            };
+            let mut ascii = Ascii(c);
+            let changed = ui
+                .horizontal(|ui| {
+                    let changed = parsed_text_ui(&mut ascii, ui, context);
+                    ui.label(format!("({})", current));
+                    changed
+                })
+                .inner;
+            match changed {
+                true => ascii.0 as u64,
+                false => return false,
             }
         }
-        if let Some(max) = options.max {
-            let as_f64 = self.to_f64();
-            let max = max.to_f64();
-            if as_f64 > max {
-                *self = T::from_f64(max);
-                changed = true;
+        false => {
+            let mut hex = Hex(current);
+            match parsed_text_ui(&mut hex, ui, context) {
+                true => hex.0,
+                false => return false,
             }
         }
+    };
+
+    let mut value = match T::from_u64(value) {
+        Some(value) => value,
+        None => {
+            warn!(
+                "ignored {:#x}, it doesn't fit into `{}`",
+                value,
+                std::any::type_name::<T>()
+            );
+            return false;
+        }
+    };
+    if let Some(min) = options.min.filter(|&min| value < min) {
+        value = min;
+    }
+    if let Some(max) = options.max.filter(|&max| value > max) {
+        value = max;
+    }
+    *number = value;
+    true
+}
+
+fn drag_value<T: Num>(
+    value: &mut T,
+    ui: &mut egui::Ui,
+    options: NumberAttributes<T>,
+) -> (bool, egui::Response) {
+    let mut widget = widgets::DragValue::new(value);
+
+    if !options.prefix.is_empty() {
+        widget = widget.prefix(options.prefix);
+    }
+    if !options.suffix.is_empty() {
+        widget = widget.suffix(options.suffix);
+    }
+
+    match (options.min, options.max) {
+        (Some(min), Some(max)) => widget = widget.clamp_range(min.to_f64()..=max.to_f64()),
+        (Some(min), None) => widget = widget.clamp_range(min.to_f64()..=f64::MAX),
+        (None, Some(max)) => widget = widget.clamp_range(f64::MIN..=max.to_f64()),
+        (None, None) => {}
+    }
+
+    if options.speed != 0.0 {
+        widget = widget.speed(options.speed);
+    } else if let Some(default_speed) = T::default_speed() {
+        widget = widget.speed(default_speed);
+    }
 
-        changed
+    let response = ui.add(widget);
+    let mut changed = response.changed();
+
+    if let Some(min) = options.min {
+        let as_f64 = value.to_f64();
+        let min = min.to_f64();
+        if as_f64 < min {
+            *value = T::from_f64(min);
+            changed = true;
+        }
     }
+    if let Some(max) = options.max {
+        let as_f64 = value.to_f64();
+        let max = max.to_f64();
+        if as_f64 > max {
+            *value = T::from_f64(max);
+            changed = true;
+        }
+    }
+
+    (changed, response)
 }
@@ -0,0 +1,44 @@
+use std::{
+    cell::RefCell,
+    rc::Rc,
+    sync::{Arc, Mutex, TryLockError},
+};
+
+use crate::{egui, utils::error_label, Context, Inspectable};
+
+/// Edits the value through the lock. Shows an error instead if the mutex is poisoned,
+/// or a note if it is currently locked somewhere else.
+impl<T: Inspectable> Inspectable for Arc<Mutex<T>> {
+    type Attributes = T::Attributes;
+
+    fn ui(&mut self, ui: &mut egui::Ui, options: Self::Attributes, context: &Context) -> bool {
+        match self.try_lock() {
+            Ok(mut value) => value.ui(ui, options, context),
+            Err(TryLockError::Poisoned(_)) => {
+                error_label(ui, "Mutex is poisoned");
+                false
+            }
+            Err(TryLockError::WouldBlock) => {
+                ui.label("(locked)");
+                false
+            }
+        }
+    }
+}
+
+/// Edits the value through the `RefCell`.
+/// If it is already borrowed somewhere else, for example because the same `Rc` is shown twice,
+/// a note is shown instead.
+impl<T: Inspectable> Inspectable for Rc<RefCell<T>> {
+    type Attributes = T::Attributes;
+
+    fn ui(&mut self, ui: &mut egui::Ui, options: Self::Attributes, context: &Context) -> bool {
+        match self.try_borrow_mut() {
+            Ok(mut value) => value.ui(ui, options, context),
+            Err(_) => {
+                ui.label("(already borrowed)");
+                false
+            }
+        }
+    }
+}
@@ -1,12 +1,16 @@
+use bevy::utils::HashMap;
 use bevy_egui::egui::Color32;
 
 use crate::{
     egui::{self, widgets},
-    utils::ui::label_button,
+    utils::ui::{label_button, with_copy_button},
 };
 use crate::{Context, Inspectable};
 use std::{
+    fmt::Display,
+    net::{IpAddr, Ipv4Addr, Ipv6Addr, SocketAddr},
     ops::{Range, RangeInclusive},
+    str::FromStr,
     time::Duration,
 };
 
@@ -20,14 +24,16 @@ pub struct StringAttributes {
 impl Inspectable for String {
     type Attributes = StringAttributes;
 
-    fn ui(&mut self, ui: &mut egui::Ui, options: Self::Attributes, _: &Context) -> bool {
-        let widget = match options.multiline {
-            false => widgets::TextEdit::singleline(self),
-            true => widgets::TextEdit::multiline(self),
-        };
+    fn ui(&mut self, ui: &mut egui::Ui, options: Self::Attributes, context: &Context) -> bool {
+        with_copy_button(ui, context, self, |ui, value| {
+            let widget = match options.multiline {
+                false => widgets::TextEdit::singleline(value),
+                true => widgets::TextEdit::multiline(value),
+            };
 
-        // PERF: this is changed if text if highlighted
-        ui.add(widget).changed()
+            // PERF: this is changed if text if highlighted
+            ui.add(widget).changed()
+        })
     }
 }
 impl<'a> Inspectable for &'a str {
@@ -41,9 +47,27 @@ impl<'a> Inspectable for &'a str {
 
 impl Inspectable for bool {
     type Attributes = ();
-    fn ui(&mut self, ui: &mut egui::Ui, _: Self::Attributes, _: &Context) -> bool {
-        ui.checkbox(self, "").changed()
+    fn ui(&mut self, ui: &mut egui::Ui, _: Self::Attributes, context: &Context) -> bool {
+        with_copy_button(ui, context, self, bool_ui)
+    }
+}
+
+fn bool_ui(ui: &mut egui::Ui, value: &mut bool) -> bool {
+    let response = ui.checkbox(value, "");
+
+    // middle-clicking anywhere right of the checkbox toggles it too,
+    // so that it can be toggled without aiming for the box
+    let row_rect = egui::Rect::from_min_max(
+        response.rect.min,
+        egui::pos2(ui.max_rect().right(), response.rect.max.y),
+    );
+    let row = ui.interact(row_rect, response.id.with("row"), egui::Sense::click());
+    if response.middle_clicked() || row.middle_clicked() {
+        *value = !*value;
+        return true;
     }
+
+    response.changed()
 }
 
 impl<T> Inspectable for RangeInclusive<T>
@@ -153,3 +177,90 @@ impl Inspectable for Duration {
         changed
     }
 }
+
+/// The text of the parsed values which are being edited, keyed by their ui and context id.
+#[derive(Default)]
+struct ParsedText(HashMap<egui::Id, String>);
+
+/// Edits `value` as text, which is only parsed once the text field loses focus.
+/// Text which can't be parsed gets a red border and is reverted.
+///
+/// Without access to the world there is nowhere to keep the text while it is edited,
+/// so it is parsed on every change instead and only valid text is kept.
+pub(super) fn parsed_text_ui<T>(value: &mut T, ui: &mut egui::Ui, context: &Context) -> bool
+where
+    T: FromStr + Display + PartialEq,
+{
+    let world = match unsafe { context.world() } {
+        Some(world) => world,
+        None => return parsed_text_ui_immediate(value, ui),
+    };
+    let mut parsed_text = world.get_resource_or_insert_with(ParsedText::default);
+
+    let id = ui.id().with(context.id());
+    let mut text = match parsed_text.0.get(&id) {
+        Some(text) => text.clone(),
+        None => value.to_string(),
+    };
+
+    let response = ui.add(widgets::TextEdit::singleline(&mut text).id(id));
+    let parsed = text.parse::<T>();
+
+    if response.has_focus() {
+        if parsed.is_err() {
+            let stroke = egui::Stroke::new(1.0, Color32::RED);
+            ui.painter().rect_stroke(response.rect, 2.0, stroke);
+        }
+        parsed_text.0.insert(id, text);
+        return false;
+    }
+
+    if parsed_text.0.contains_key(&id) {
+        parsed_text.0.remove(&id);
+    }
+    if let (true, Ok(parsed)) = (response.lost_focus(), parsed) {
+        if parsed != *value {
+            *value = parsed;
+            return true;
+        }
+    }
+
+    false
+}
+
+fn parsed_text_ui_immediate<T>(value: &mut T, ui: &mut egui::Ui) -> bool
+where
+    T: FromStr + Display + PartialEq,
+{
+    let mut text = value.to_string();
+    let response = ui.text_edit_singleline(&mut text);
+    if !response.changed() {
+        return false;
+    }
+    match text.parse::<T>() {
+        Ok(parsed) if parsed != *value => {
+            *value = parsed;
+            true
+        }
+        Ok(_) => false,
+        Err(_) => {
+            let stroke = egui::Stroke::new(1.0, Color32::RED);
+            ui.painter().rect_stroke(response.rect, 2.0, stroke);
+            false
+        }
+    }
+}
+
+macro_rules! impl_for_parsed_text {
+    ($($ty:ty),*) => {
+        $(impl Inspectable for $ty {
+            type Attributes = ();
+
+            fn ui(&mut self, ui: &mut egui::Ui, _: Self::Attributes, context: &Context) -> bool {
+                parsed_text_ui(self, ui, context)
+            }
+        })*
+    };
+}
+
+impl_for_parsed_text!(IpAddr, Ipv4Addr, Ipv6Addr, SocketAddr, char);
@@ -1,11 +1,14 @@
 use bevy::{prelude::*, utils::HashMap};
 use bevy_egui::egui;
+use egui::{Color32, Pos2, Sense, Widget};
 
 use crate::{Context, Inspectable};
 
 #[derive(Clone)]
 pub struct QuatAttributes {
     pub display: QuatDisplay,
+    /// Shows a trackball next to the values, which can be dragged to rotate the quaternion
+    pub gizmo: bool,
 }
 
 #[derive(Copy, Clone)]
@@ -20,6 +23,7 @@ impl Default for QuatAttributes {
     fn default() -> Self {
         QuatAttributes {
             display: QuatDisplay::Euler,
+            gizmo: false,
         }
     }
 }
@@ -36,105 +40,134 @@ impl Inspectable for Quat {
     type Attributes = QuatAttributes;
 
     fn ui(&mut self, ui: &mut egui::Ui, options: Self::Attributes, context: &Context) -> bool {
-        match options.display {
-            QuatDisplay::Raw => {
-                let mut vec4 = Vec4::from(*self);
-                let changed = ui
-                    .vertical(|ui| vec4.ui(ui, Default::default(), context))
-                    .inner;
-                if changed {
-                    *self = Quat::from(vec4).normalize();
+        if !options.gizmo {
+            return quat_values_ui(self, ui, options.display, context);
+        }
+
+        ui.horizontal(|ui| {
+            let gizmo_changed = ui.add(RotationGizmo::new(self, 80.0)).changed();
+            if gizmo_changed {
+                // the edited values are recomputed from the new rotation
+                if let Some(world) = unsafe { context.world() } {
+                    let mut states = world.get_resource_or_insert_with(QuatEditStates::default);
+                    states.0.remove(&context.id());
                 }
-                changed
             }
-            QuatDisplay::Euler => {
-                let world = expect_world!(ui, context, "Quat");
-                let mut states = world.get_resource_or_insert_with(QuatEditStates::default);
-                let state = states
-                    .0
-                    .entry(context.id())
-                    .or_insert_with(|| QuatEditState::Euler(to_euler_angles(*self)));
-
-                let euler_angles = match state {
-                    QuatEditState::Euler(euler) => euler,
-                    _ => unreachable!("invalid quat edit state"),
-                };
-
-                let changed = euler_angles.ui(ui, Default::default(), context);
-                if changed {
-                    *self = from_euler_angles(*euler_angles);
-                }
-                changed
+
+            let changed = ui
+                .vertical(|ui| quat_values_ui(self, ui, options.display, context))
+                .inner;
+            gizmo_changed || changed
+        })
+        .inner
+    }
+}
+
+/// The numeric representation of the quaternion according to `display`
+fn quat_values_ui(
+    value: &mut Quat,
+    ui: &mut egui::Ui,
+    display: QuatDisplay,
+    context: &Context,
+) -> bool {
+    match display {
+        QuatDisplay::Raw => {
+            let mut vec4 = Vec4::from(*value);
+            let changed = ui
+                .vertical(|ui| vec4.ui(ui, Default::default(), context))
+                .inner;
+            if changed {
+                *value = Quat::from(vec4).normalize();
             }
-            QuatDisplay::YawPitchRoll => {
-                let world = expect_world!(ui, context, "Quat");
-                let mut states = world.get_resource_or_insert_with(QuatEditStates::default);
-                let state = states
-                    .0
-                    .entry(context.id())
-                    .or_insert_with(|| QuatEditState::YawPitchRoll(yaw_pitch_roll(*self)));
-
-                let (yaw, pitch, roll) = match state {
-                    QuatEditState::YawPitchRoll((y, p, r)) => (y, p, r),
-                    _ => unreachable!("invalid quat edit state"),
-                };
-
-                let mut changed = false;
-                ui.vertical(|ui| {
-                    egui::Grid::new("ypr grid").show(ui, |ui| {
-                        ui.label("Yaw");
-                        changed |= ui.drag_angle(yaw).changed();
-                        ui.end_row();
-                        ui.label("Pitch").changed();
-                        changed |= ui.drag_angle(pitch).changed();
-                        ui.end_row();
-                        ui.label("Roll");
-                        changed |= ui.drag_angle(roll).changed();
-                        ui.end_row();
-                    });
-                });
+            changed
+        }
+        QuatDisplay::Euler => {
+            let world = expect_world!(ui, context, "Quat");
+            let mut states = world.get_resource_or_insert_with(QuatEditStates::default);
+            let state = states
+                .0
+                .entry(context.id())
+                .or_insert_with(|| QuatEditState::Euler(to_euler_angles(*value)));
 
-                if changed {
-                    *self = Quat::from_rotation_ypr(*yaw, *pitch, *roll);
-                }
+            let euler_angles = match state {
+                QuatEditState::Euler(euler) => euler,
+                _ => unreachable!("invalid quat edit state"),
+            };
 
-                changed
+            let changed = euler_angles.ui(ui, Default::default(), context);
+            if changed {
+                *value = from_euler_angles(*euler_angles);
             }
-            QuatDisplay::AxisAngle => {
-                let world = expect_world!(ui, context, "Quat");
-                let mut states = world.get_resource_or_insert_with(QuatEditStates::default);
-                let state = states
-                    .0
-                    .entry(context.id())
-                    .or_insert_with(|| QuatEditState::AxisAngle(self.to_axis_angle()));
-
-                let (axis, angle) = match state {
-                    QuatEditState::AxisAngle((axis, angle)) => (axis, angle),
-                    _ => unreachable!("invalid quat edit state"),
-                };
-
-                let mut changed = false;
-                ui.vertical(|ui| {
-                    egui::Grid::new("axis-angle quat").show(ui, |ui| {
-                        ui.label("Axis");
-                        changed |= axis.ui(ui, Default::default(), context);
-                        ui.end_row();
-                        ui.label("Angle");
-                        changed |= ui.drag_angle(angle).changed();
-                        ui.end_row();
-                    });
+            changed
+        }
+        QuatDisplay::YawPitchRoll => {
+            let world = expect_world!(ui, context, "Quat");
+            let mut states = world.get_resource_or_insert_with(QuatEditStates::default);
+            let state = states
+                .0
+                .entry(context.id())
+                .or_insert_with(|| QuatEditState::YawPitchRoll(yaw_pitch_roll(*value)));
+
+            let (yaw, pitch, roll) = match state {
+                QuatEditState::YawPitchRoll((y, p, r)) => (y, p, r),
+                _ => unreachable!("invalid quat edit state"),
+            };
+
+            let mut changed = false;
+            ui.vertical(|ui| {
+                egui::Grid::new("ypr grid").show(ui, |ui| {
+                    ui.label("Yaw");
+                    changed |= ui.drag_angle(yaw).changed();
+                    ui.end_row();
+                    ui.label("Pitch").changed();
+                    changed |= ui.drag_angle(pitch).changed();
+                    ui.end_row();
+                    ui.label("Roll");
+                    changed |= ui.drag_angle(roll).changed();
+                    ui.end_row();
                 });
-                if changed {
-                    *self = Quat::from_axis_angle(axis.normalize(), *angle);
-                }
-                changed
+            });
+
+            if changed {
+                *value = Quat::from_rotation_ypr(*yaw, *pitch, *roll);
             }
+
+            changed
+        }
+        QuatDisplay::AxisAngle => {
+            let world = expect_world!(ui, context, "Quat");
+            let mut states = world.get_resource_or_insert_with(QuatEditStates::default);
+            let state = states
+                .0
+                .entry(context.id())
+                .or_insert_with(|| QuatEditState::AxisAngle(value.to_axis_angle()));
+
+            let (axis, angle) = match state {
+                QuatEditState::AxisAngle((axis, angle)) => (axis, angle),
+                _ => unreachable!("invalid quat edit state"),
+            };
+
+            let mut changed = false;
+            ui.vertical(|ui| {
+                egui::Grid::new("axis-angle quat").show(ui, |ui| {
+                    ui.label("Axis");
+                    changed |= axis.ui(ui, Default::default(), context);
+                    ui.end_row();
+                    ui.label("Angle");
+                    changed |= ui.drag_angle(angle).changed();
+                    ui.end_row();
+                });
+            });
+            if changed {
+                *value = Quat::from_axis_angle(axis.normalize(), *angle);
+            }
+            changed
         }
     }
 }
 
 // yaw - Z, pitch - Y, roll - X
-fn to_euler_angles(val: Quat) -> Vec3 {
+pub(crate) fn to_euler_angles(val: Quat) -> Vec3 {
     let (yaw, pitch, roll) = yaw_pitch_roll(val);
     Vec3::new(roll, pitch, yaw)
 }
@@ -162,3 +195,67 @@ fn yaw_pitch_roll(q: Quat) -> (f32, f32, f32) {
 
     (yaw, pitch, roll)
 }
+
+/// A trackball showing the rotated coordinate axes, which rotates the quaternion when dragged.
+struct RotationGizmo<'a> {
+    size: egui::Vec2,
+    value: &'a mut Quat,
+}
+impl<'a> RotationGizmo<'a> {
+    /// Radians of rotation per point dragged
+    const SENSITIVITY: f32 = 0.01;
+
+    fn new(value: &'a mut Quat, size: f32) -> Self {
+        RotationGizmo {
+            size: egui::Vec2::new(size, size),
+            value,
+        }
+    }
+}
+
+impl Widget for RotationGizmo<'_> {
+    fn ui(self, ui: &mut egui::Ui) -> egui::Response {
+        let (rect, mut response) = ui.allocate_exact_size(self.size, Sense::drag());
+
+        if response.dragged() {
+            let delta = ui.input().pointer.delta();
+            // dragging right rotates around the up axis, dragging down around the right axis
+            let axis = Vec3::new(delta.y, delta.x, 0.0);
+            if axis.length_squared() > 0.0 {
+                let rotation =
+                    Quat::from_axis_angle(axis.normalize(), axis.length() * Self::SENSITIVITY);
+                *self.value = (rotation * *self.value).normalize();
+                response.mark_changed();
+            }
+        }
+
+        let visuals = ui.style().interact(&response);
+        let painter = ui.painter();
+        let center = rect.center();
+        let radius = rect.width() / 2.0 - 2.0;
+        painter.circle(center, radius, visuals.bg_fill, visuals.fg_stroke);
+
+        let axes = [
+            (Vec3::X, Color32::from_rgb(230, 70, 70)),
+            (Vec3::Y, Color32::from_rgb(70, 200, 70)),
+            (Vec3::Z, Color32::from_rgb(70, 110, 240)),
+        ];
+        let mut axes: Vec<_> = axes
+            .iter()
+            .map(|&(axis, color)| (*self.value * axis, color))
+            .collect();
+        // the axes further back are painted first, and dimmed
+        axes.sort_by(|(a, _), (b, _)| a.z.partial_cmp(&b.z).unwrap_or(std::cmp::Ordering::Equal));
+        for (axis, color) in axes {
+            let end = Pos2::new(center.x + axis.x * radius, center.y - axis.y * radius);
+            let color = match axis.z < 0.0 {
+                true => color.linear_multiply(0.4),
+                false => color,
+            };
+            painter.line_segment([center, end], (2.0, color));
+            painter.circle_filled(end, 3.0, color);
+        }
+
+        response
+    }
+}
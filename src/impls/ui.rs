@@ -1,9 +1,9 @@
-use bevy::prelude::*;
+use bevy::{prelude::*, utils::HashSet};
 use bevy_egui::egui;
 
 use crate::Inspectable;
 
-use super::NumberAttributes;
+use super::{NumberAttributes, StringAttributes};
 
 impl_for_simple_enum!(Display: Flex, None);
 impl_for_simple_enum!(bevy::ui::FocusPolicy: Block, Pass);
@@ -39,8 +39,15 @@ impl_for_simple_enum!(
 );
 
 impl_for_struct_delegate_fields!(TextAlignment: vertical, horizontal);
-impl_for_struct_delegate_fields!(TextStyle: font, font_size, color);
-impl_for_struct_delegate_fields!(TextSection: value, style);
+impl_for_struct_delegate_fields!(
+    TextStyle: font,
+    font_size with NumberAttributes::positive(),
+    color
+);
+impl_for_struct_delegate_fields!(
+    TextSection: value with StringAttributes { multiline: true },
+    style
+);
 impl_for_struct_delegate_fields!(Text: sections, alignment);
 
 impl_for_struct_delegate_fields!(
@@ -54,10 +61,10 @@ impl_for_struct_delegate_fields!(
     align_self,
     align_content,
     justify_content,
-    //position,
-    //margin,
-    //padding,
-    //border,
+    position,
+    margin,
+    padding,
+    border,
     flex_grow with NumberAttributes::positive(),
     flex_shrink with NumberAttributes::positive(),
     flex_basis,
@@ -93,6 +100,92 @@ impl<T: Inspectable + Reflect + PartialEq> Inspectable for Size<T> {
     }
 }
 
+/// The ids of the rects whose sides are linked, so that editing one side edits all of them.
+#[derive(Default)]
+struct LinkedRects(HashSet<egui::Id>);
+
+/// The sides are laid out like a box, with a toggle in the middle for linking them.
+/// Linking requires access to the world.
+impl<T: Inspectable + Reflect + PartialEq + Clone> Inspectable for Rect<T> {
+    type Attributes = T::Attributes;
+
+    fn ui(
+        &mut self,
+        ui: &mut bevy_egui::egui::Ui,
+        options: Self::Attributes,
+        context: &crate::Context,
+    ) -> bool {
+        let id = ui.id().with(context.id());
+        let world = unsafe { context.world() };
+        let mut linked = match &world {
+            Some(world) => {
+                matches!(world.get_resource::<LinkedRects>(), Some(linked) if linked.0.contains(&id))
+            }
+            None => false,
+        };
+
+        let mut changed = false;
+        let mut edited = None;
+        let mut side_ui = |ui: &mut egui::Ui, value: &mut T, i: u64| {
+            if value.ui(ui, options.clone(), &context.with_id(i)) {
+                changed = true;
+                edited = Some(value.clone());
+            }
+        };
+
+        let mut toggled = false;
+        egui::Grid::new(context.id()).show(ui, |ui| {
+            ui.label("");
+            side_ui(ui, &mut self.top, 0);
+            ui.label("");
+            ui.end_row();
+
+            side_ui(ui, &mut self.left, 1);
+            match world.is_some() {
+                true => {
+                    let response = ui.selectable_label(linked, "🔗");
+                    if response.on_hover_text("Edit all sides at once").clicked() {
+                        linked = !linked;
+                        toggled = true;
+                    }
+                }
+                false => {
+                    ui.label("");
+                }
+            }
+            side_ui(ui, &mut self.right, 2);
+            ui.end_row();
+
+            ui.label("");
+            side_ui(ui, &mut self.bottom, 3);
+            ui.label("");
+            ui.end_row();
+        });
+
+        if toggled && linked {
+            edited = Some(self.top.clone());
+        }
+        if let (true, Some(value)) = (linked, edited) {
+            let sides = [&self.left, &self.right, &self.top, &self.bottom];
+            changed |= sides.iter().any(|&side| *side != value);
+            self.left = value.clone();
+            self.right = value.clone();
+            self.top = value.clone();
+            self.bottom = value;
+        }
+
+        if let (true, Some(world)) = (toggled, world) {
+            let mut linked_rects = world.get_resource_or_insert_with(LinkedRects::default);
+            match linked {
+                true => linked_rects.0.insert(id),
+                false => linked_rects.0.remove(&id),
+            };
+        }
+
+        changed
+    }
+}
+
 impl Inspectable for Val {
     type Attributes = ();
 
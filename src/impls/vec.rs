@@ -1,7 +1,7 @@
 use std::ops::RangeInclusive;
 
 use crate::{Context, Inspectable};
-use bevy::math::{Vec2, Vec3, Vec4};
+use bevy::math::{Vec2, Vec3, Vec3A, Vec4};
 use bevy_egui::egui::{self, containers, Rect};
 use egui::{Pos2, Sense, Widget};
 
@@ -161,6 +161,31 @@ impl Inspectable for Vec3 {
                 changed |= self.z.ui(&mut ui[2], options.map(|vec| vec.z), context);
             });
         });
+
+        if options.direction {
+            if changed && options.normalize_on_edit {
+                *self = self.normalize_or_zero();
+            }
+            let normalize = egui::Button::new("Normalize").enabled(!self.is_normalized());
+            if ui.add(normalize).clicked() {
+                *self = self.normalize_or_zero();
+                changed = true;
+            }
+        }
+        changed
+    }
+}
+
+/// Edited like a [`Vec3`], the padding isn't touched.
+impl Inspectable for Vec3A {
+    type Attributes = NumberAttributes<Vec3A>;
+
+    fn ui(&mut self, ui: &mut egui::Ui, options: Self::Attributes, context: &Context) -> bool {
+        let mut value = Vec3::from(*self);
+        let changed = value.ui(ui, options.map(|&vec| Vec3::from(vec)), context);
+        if changed {
+            *self = Vec3A::from(value);
+        }
         changed
     }
 }
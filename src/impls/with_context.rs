@@ -1,15 +1,18 @@
 use std::collections::hash_map::Entry;
 
 use crate::{
+    reflect::visit_reflect,
     utils::{self, image_texture_conversion},
+    world_inspector::snapshot::for_each_reflected_component,
     Context, Inspectable,
 };
 use bevy::{
     app::Events,
-    asset::{Asset, HandleId},
+    asset::{Asset, HandleId, LoadState},
     prelude::*,
+    reflect::{TypeRegistryArc, TypeUuid},
     render::texture::Texture,
-    utils::HashMap,
+    utils::{HashMap, HashSet, Uuid},
 };
 use bevy_egui::{
     egui::{self, Color32},
@@ -18,45 +21,193 @@ use bevy_egui::{
 use egui::TextureId;
 pub use image::imageops::FilterType;
 
-macro_rules! expect_handle {
-    ($ui:ident, $assets:ident, $method:ident $asset:ident) => {
-        match $assets.$method($asset.clone()) {
-            Some(val) => val,
-            None => {
-                utils::error_label($ui, format!("No value for handle {:?}", $asset));
-                return false;
-            }
-        }
+macro_rules! asset_type_names {
+    ($uuid:ident: $($ty:ty),* $(,)?) => {
+        $(if *$uuid == <$ty as TypeUuid>::TYPE_UUID {
+            return Some(stringify!($ty));
+        })*
     };
 }
 
+/// Name of the asset type with the uuid `uuid`, if it's one of bevy's asset types.
+fn asset_type_name(uuid: &Uuid) -> Option<&'static str> {
+    asset_type_names!(uuid: Mesh, Texture, StandardMaterial, ColorMaterial, TextureAtlas, Font, Shader);
+    None
+}
+
+/// The asset type and number of `id`, or the path it was loaded from.
+fn handle_id_label(id: HandleId, world: Option<&World>) -> String {
+    match id {
+        HandleId::Id(uuid, id) => match asset_type_name(&uuid) {
+            Some(name) => format!("{} #{}", name, id),
+            None => format!("{} #{}", uuid, id),
+        },
+        HandleId::AssetPathId(_) => world
+            .and_then(|world| world.get_resource::<AssetServer>())
+            .and_then(|asset_server| {
+                let path = asset_server.get_handle_path(id)?;
+                Some(match path.label() {
+                    Some(label) => format!("{}#{}", path.path().display(), label),
+                    None => path.path().display().to_string(),
+                })
+            })
+            .unwrap_or_else(|| "<asset path id>".to_string()),
+    }
+}
+
 impl Inspectable for HandleId {
     type Attributes = ();
 
-    fn ui(&mut self, ui: &mut egui::Ui, _: Self::Attributes, _: &Context) -> bool {
-        ui.label("<handle id>");
+    fn ui(&mut self, ui: &mut egui::Ui, _: Self::Attributes, context: &Context) -> bool {
+        let world = unsafe { context.world() };
+        ui.label(handle_id_label(*self, world.as_deref()));
         false
     }
 }
 
+impl Inspectable for HandleUntyped {
+    type Attributes = ();
+
+    fn ui(&mut self, ui: &mut egui::Ui, _: Self::Attributes, context: &Context) -> bool {
+        let mut id = self.id;
+        id.ui(ui, (), context)
+    }
+}
+
 impl<T: Asset + Inspectable> Inspectable for Handle<T> {
     type Attributes = T::Attributes;
 
     fn ui(&mut self, ui: &mut egui::Ui, options: Self::Attributes, context: &Context) -> bool {
-        if self.id == HandleId::default::<T>() {
+        // without a world there are no assets to pick, but the default handle can still be shown
+        if self.id == HandleId::default::<T>() && unsafe { context.world() }.is_none() {
             ui.label("<default handle>");
             return false;
         }
 
         let world = expect_world!(ui, context, "Handle<T>");
+        let mut changed = asset_picker(ui, self, world, context);
+
+        if self.id == HandleId::default::<T>() {
+            return changed;
+        }
+
         let mut assets = world.get_resource_mut::<Assets<T>>().unwrap();
+        match assets.get_mut(self.clone()) {
+            Some(value) => changed |= value.ui(ui, options, context),
+            None => {
+                ui.label("(not loaded)");
+            }
+        }
+        changed
+    }
+}
+
+/// Combo box for pointing `handle` to any of the loaded assets of type `T`.
+/// The default handle is shown as `<default handle>`.
+pub(crate) fn asset_picker<T: Asset>(
+    ui: &mut egui::Ui,
+    handle: &mut Handle<T>,
+    world: &World,
+    context: &Context,
+) -> bool {
+    let assets = match world.get_resource::<Assets<T>>() {
+        Some(assets) => assets,
+        None => return false,
+    };
+
+    let label = |id: HandleId| match id == HandleId::default::<T>() {
+        true => "<default handle>".to_string(),
+        false => handle_id_label(id, Some(world)),
+    };
+
+    let mut selected = None;
+    egui::ComboBox::from_id_source(context.id().with("asset picker"))
+        .selected_text(label(handle.id))
+        .show_ui(ui, |ui| {
+            let mut ids: Vec<_> = assets.ids().map(|id| (label(id), id)).collect();
+            ids.sort_by(|(a, _), (b, _)| a.cmp(b));
+            for (label, id) in ids {
+                if ui.selectable_label(id == handle.id, label).clicked() && id != handle.id {
+                    selected = Some(id);
+                }
+            }
+        });
 
-        let value = expect_handle!(ui, assets, get_mut self);
+    match selected {
+        Some(id) => {
+            *handle = assets.get_handle(id);
+            true
+        }
+        None => false,
+    }
+}
 
-        value.ui(ui, options, context)
+/// Read-only list of the assets referenced by components, together with their load state.
+/// Only components registered with `#[reflect(Component)]` (which includes `Handle<T>`) are searched
+/// and only assets loaded from a path are listed. Failed loads are highlighted in red.
+impl Inspectable for AssetServer {
+    type Attributes = ();
+
+    fn ui(&mut self, ui: &mut egui::Ui, _: Self::Attributes, context: &Context) -> bool {
+        let world = expect_world!(ui, context, "AssetServer");
+
+        let mut assets: Vec<(String, LoadState)> = referenced_asset_paths(world)
+            .into_iter()
+            .map(|id| (handle_id_label(id, Some(world)), self.get_load_state(id)))
+            .collect();
+        if assets.is_empty() {
+            ui.label("No assets are referenced by components");
+            return false;
+        }
+        assets.sort_by(|(a, _), (b, _)| a.cmp(b));
+
+        let failed = assets
+            .iter()
+            .filter(|(_, state)| *state == LoadState::Failed)
+            .count();
+        ui.vertical(|ui| {
+            ui.label(format!("{} assets, {} failed", assets.len(), failed));
+            egui::Grid::new(context.id()).show(ui, |ui| {
+                for (path, state) in assets {
+                    let color = match state {
+                        LoadState::NotLoaded => Color32::GRAY,
+                        LoadState::Loading => Color32::YELLOW,
+                        LoadState::Loaded => Color32::GREEN,
+                        LoadState::Failed => Color32::RED,
+                    };
+                    ui.label(path);
+                    ui.colored_label(color, format!("{:?}", state));
+                    ui.end_row();
+                }
+            });
+        });
+        false
     }
 }
 
+/// The ids of the handles with an asset path in the reflectable components of all entities.
+fn referenced_asset_paths(world: &World) -> HashSet<HandleId> {
+    let type_registry = world.get_resource::<TypeRegistryArc>().unwrap().clone();
+    let type_registry = type_registry.internal.read();
+
+    let mut ids = HashSet::default();
+    for_each_reflected_component(
+        world,
+        &type_registry,
+        |_| true,
+        |_, _, value| {
+            visit_reflect(value, &mut |value| {
+                if let Some(&id) = value.downcast_ref::<HandleId>() {
+                    if matches!(id, HandleId::AssetPathId(_)) {
+                        ids.insert(id);
+                    }
+                }
+            })
+        },
+    );
+    ids
+}
+
 #[derive(Default)]
 struct ScaledDownTextures {
     textures: HashMap<Handle<Texture>, Handle<Texture>>,
@@ -67,18 +67,29 @@ mod utils;
 /// Utitly types implementing [`Inspectable`](crate::Inspectable)
 pub mod widgets;
 
+mod default_plugins;
+mod disabled_systems;
 #[allow(missing_docs)]
 mod impls;
 mod plugin;
+mod run_conditions;
+mod schedule_inspector;
 
 /// configuration for the [`WorldInspectorPlugin`](crate::world_inspector::WorldInspectorPlugin)
 mod world_inspector;
 
 use std::hash::Hasher;
 
-use bevy::prelude::{AppBuilder, World};
+use bevy::{
+    prelude::{AppBuilder, World},
+    utils::HashSet,
+};
 use egui::CtxRef;
-pub use world_inspector::{InspectableRegistry, WorldInspectorParams, WorldInspectorPlugin};
+pub use world_inspector::{
+    InspectableRegistration, InspectableRegistry, InspectorHovered, PanelSide,
+    WorldInspectorContext, WorldInspectorFilter, WorldInspectorParams, WorldInspectorPlugin,
+    WorldInspectorViews,
+};
 
 /// [`Inspectable`] implementation for foreign types implementing [`Reflect`](bevy::reflect::Reflect)
 pub mod reflect;
@@ -88,7 +99,11 @@ pub use bevy_egui::egui;
 
 /// Derives the [`Inspectable`](Inspectable) trait.
 pub use bevy_inspector_egui_derive::Inspectable;
-pub use plugin::InspectorPlugin;
+pub use default_plugins::DefaultInspectorPlugins;
+pub use disabled_systems::{inspector_gated, DisabledSystems};
+pub use plugin::{inspect_value, InspectorPlugin};
+pub use run_conditions::RunConditions;
+pub use schedule_inspector::ScheduleInspectorPlugin;
 
 /// Attributes for the built-in [`Inspectable`](Inspectable) implementations
 pub mod options {
@@ -107,6 +122,18 @@ pub struct Context<'a> {
 
     /// Something to distinguish between siblings.
     pub id: Option<u64>,
+
+    /// Whether dragged numbers should only be written back once the drag is released
+    commit_on_release: bool,
+    /// Whether primitive values should be shown with a button copying them to the clipboard
+    copy_buttons: bool,
+
+    /// How many nested inspectables this context is inside of
+    depth: usize,
+    /// How deep inspectables may be nested before they bail out
+    max_depth: usize,
+    /// The ids of the fields whose values differ between the selected entities
+    mixed: Option<&'a HashSet<u64>>,
 }
 impl<'a> Context<'a> {
     /// Gives mutable access to the [bevy::ecs::world::World]
@@ -124,6 +151,11 @@ impl<'a> Context<'a> {
             ui_ctx: Some(ui_ctx),
             world: Some(world as *mut _),
             id: None,
+            commit_on_release: false,
+            copy_buttons: false,
+            depth: 0,
+            max_depth: usize::MAX,
+            mixed: None,
         }
     }
     /// Create a new context with access to the world
@@ -135,6 +167,11 @@ impl<'a> Context<'a> {
             ui_ctx,
             world: Some(world),
             id: None,
+            commit_on_release: false,
+            copy_buttons: false,
+            depth: 0,
+            max_depth: usize::MAX,
+            mixed: None,
         }
     }
 
@@ -144,6 +181,11 @@ impl<'a> Context<'a> {
             ui_ctx,
             world: None,
             id: None,
+            commit_on_release: false,
+            copy_buttons: false,
+            depth: 0,
+            max_depth: usize::MAX,
+            mixed: None,
         }
     }
 
@@ -162,6 +204,77 @@ impl<'a> Context<'a> {
         }
     }
 
+    /// Same context but with numbers only being written back once they are no longer dragged.
+    pub fn with_commit_on_release(&self, commit_on_release: bool) -> Self {
+        Context {
+            commit_on_release,
+            ..*self
+        }
+    }
+
+    /// Whether dragged numbers should only be written back once the drag is released,
+    /// see [`Context::with_commit_on_release`].
+    pub fn commit_on_release(&self) -> bool {
+        self.commit_on_release
+    }
+
+    /// Same context but with numbers, strings and bools showing a button copying their value.
+    pub fn with_copy_buttons(&self, copy_buttons: bool) -> Self {
+        Context {
+            copy_buttons,
+            ..*self
+        }
+    }
+
+    /// Whether primitive values should show a copy button, see [`Context::with_copy_buttons`].
+    pub fn copy_buttons(&self) -> bool {
+        self.copy_buttons
+    }
+
+    /// Same context but with nested inspectables only being displayed up to `max_depth` levels deep.
+    pub fn with_max_depth(&self, max_depth: usize) -> Self {
+        Context { max_depth, ..*self }
+    }
+
+    /// Same context but one level deeper, for passing to the fields of a struct or elements of a collection.
+    pub fn nested(&self) -> Self {
+        Context {
+            depth: self.depth + 1,
+            ..*self
+        }
+    }
+
+    /// Whether the maximum depth of nested inspectables is reached, see [`Context::with_max_depth`].
+    /// In that case, an implementation should display `(max depth reached)` instead of its contents.
+    pub fn max_depth_reached(&self) -> bool {
+        self.depth >= self.max_depth
+    }
+
+    /// Same context but with the fields whose [id](struct.Context.html#structfield.id) is in `mixed` being shown as `(mixed)`,
+    /// used when editing multiple entities whose values differ.
+    pub fn with_mixed(&self, mixed: &'a HashSet<u64>) -> Self {
+        Context {
+            mixed: Some(mixed),
+            ..*self
+        }
+    }
+
+    /// Whether the value of this field differs between the edited entities, see [`Context::with_mixed`].
+    pub fn is_mixed(&self) -> bool {
+        match (self.mixed, self.id) {
+            (Some(mixed), Some(id)) => mixed.contains(&id),
+            _ => false,
+        }
+    }
+
+    /// `label`, followed by `(mixed)` if the field [is mixed](Context::is_mixed).
+    pub fn mixed_label(&self, label: &str) -> String {
+        match self.is_mixed() {
+            true => format!("{} (mixed)", label),
+            false => label.to_string(),
+        }
+    }
+
     /// Returns the [id](struct.Context.html#structfield.id) if present, otherwise a dummy id.
     pub fn id(&self) -> egui::Id {
         let dummy_id = egui::Id::new(42);
@@ -178,10 +291,15 @@ impl<'a> Context<'a> {
 /// ## Default attributes
 /// - **ignore**: hides the field in the inspector
 /// - **label**: provides a label instead of using the field name
+/// - **tooltip**: `#[inspectable(tooltip = "text")]` shows the text when hovering the label of the field
 /// - **read_only**: disables the UI
-/// - **collapse**: wraps the ui in an [`egui::CollapsingHeader`].
+/// - **collapse**: wraps the ui in an [`egui::CollapsingHeader`], which starts out closed. `collapsed` does the same.
 /// - **default**: only for enums, specifies the default value when selecting a new variant
-/// - **wrapper**: wrap field UI in a custom function. Demo in the [rust_types example](https://github.com/jakobhellermann/bevy-inspector-egui/blob/main/examples/rust_types.rs#L20).
+/// - **variant_label**: only on enums, `#[inspectable(variant_label = "label_fn")]` uses `label_fn(variant_name)` as the text for each variant
+/// - **radio**: only on enums, `#[inspectable(radio)]` selects the variant using radio buttons instead of a combobox
+/// - **button**: only on structs, `#[inspectable(button = "respawn")]` shows a button calling `self.respawn()`. On a field, the button is shown instead of the field.
+/// - **computed**: only on structs, `#[inspectable(computed = "speed")]` shows the result of `self.speed()`, which has to implement `Display`, read-only after the fields
+/// - **wrapper**: wrap field UI in a custom function. Demo in the [rust_types example](https://github.com/jakobhellermann/bevy-inspector-egui/blob/main/examples/rust_types.rs#L20). On a struct with a single field, `#[inspectable(wrapper)]` shows the field directly instead of a grid with a label, e.g. for `struct Health(f32)`.
 pub trait Inspectable {
     /// The `Attributes` associated type specifies what attributes can be passed to a field.
     /// See the following snippet for an example:
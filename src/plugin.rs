@@ -148,6 +148,7 @@ fn shared_access_ui<T>(
     data: Option<ResMut<T>>,
     egui_context: ResMut<EguiContext>,
     inspector_windows: Res<InspectorWindows>,
+    registry: Res<InspectableRegistry>,
 ) where
     T: Inspectable + Send + Sync + 'static,
 {
@@ -165,6 +166,8 @@ fn shared_access_ui<T>(
         .show(egui_context.ctx(), |ui| {
             default_settings(ui);
 
+            resource_file_ui(ui, &registry, &mut *data);
+
             let context = Context::new_shared(Some(ctx));
             data.ui(ui, T::Attributes::default(), &context);
         });
@@ -195,6 +198,8 @@ where
         None => return,
     };
 
+    let registry = unsafe { (*world_ptr).get_resource::<InspectableRegistry>() };
+
     let mut changed = false;
 
     egui::Window::new(type_name)
@@ -204,7 +209,10 @@ where
             default_settings(ui);
 
             let value = data.get_mut_silent();
-            changed = value.ui(ui, T::Attributes::default(), &context);
+            if let Some(registry) = registry {
+                changed |= resource_file_ui(ui, registry, value);
+            }
+            changed |= value.ui(ui, T::Attributes::default(), &context);
         });
 
     if changed {
@@ -212,6 +220,80 @@ where
     }
 }
 
+/// Buttons for saving `value` to and reloading it from its file, if it has one registered with
+/// [`InspectableRegistry::register_resource_file`]. Returns whether it was reloaded.
+fn resource_file_ui<T: 'static>(
+    ui: &mut egui::Ui,
+    registry: &InspectableRegistry,
+    value: &mut T,
+) -> bool {
+    let resource_file = match registry.resource_files.get(&TypeId::of::<T>()) {
+        Some(resource_file) => resource_file,
+        None => return false,
+    };
+
+    let mut reloaded = false;
+    ui.horizontal(|ui| {
+        if ui.button("Reload from file").clicked() {
+            match resource_file.reload(value) {
+                Ok(()) => reloaded = true,
+                Err(error) => warn!(
+                    "failed to reload {}: {}",
+                    resource_file.path.display(),
+                    error
+                ),
+            }
+        }
+        if ui.button("Save to file").clicked() {
+            match resource_file.save(value) {
+                Ok(()) => info!("saved {}", resource_file.path.display()),
+                Err(error) => warn!("failed to save {}: {}", resource_file.path.display(), error),
+            }
+        }
+    })
+    .response
+    .on_hover_text(resource_file.path.display().to_string());
+    ui.separator();
+
+    reloaded
+}
+
+/// Displays a window titled `title` for editing `value` in place, for values that aren't stored in the world.
+/// It needs to be called every frame the window should be shown, and returns whether the value changed.
+///
+/// The [`Context`] has no access to the world, so types like `Handle<T>` can't be inspected this way.
+/// ```rust,no_run
+/// use bevy::prelude::*;
+/// use bevy_inspector_egui::{bevy_egui::EguiContext, inspect_value, Inspectable};
+///
+/// #[derive(Inspectable, Default)]
+/// struct EditorState {
+///     zoom: f32,
+///     show_grid: bool,
+/// }
+///
+/// fn editor_ui(egui_context: Res<EguiContext>, mut state: Local<EditorState>) {
+///     if inspect_value(egui_context.ctx(), "Editor", &mut *state) {
+///         println!("zoom is now {}", state.zoom);
+///     }
+/// }
+/// ```
+pub fn inspect_value<T: Inspectable>(ctx: &egui::CtxRef, title: &str, value: &mut T) -> bool {
+    let mut changed = false;
+
+    egui::Window::new(title)
+        .resizable(false)
+        .scroll(true)
+        .show(ctx, |ui| {
+            default_settings(ui);
+
+            let context = Context::new_shared(Some(ctx));
+            changed = value.ui(ui, T::Attributes::default(), &context);
+        });
+
+    changed
+}
+
 pub(crate) fn default_settings(ui: &mut egui::Ui) {
     ui.style_mut().wrap = Some(false);
 }
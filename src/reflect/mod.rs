@@ -1,7 +1,8 @@
 use std::ops::{Deref, DerefMut};
 
 use bevy::prelude::*;
-use bevy::reflect::{List, Map, Tuple};
+use bevy::reflect::{List, Map, ReflectMut, ReflectRef, Tuple};
+use bevy::utils::{HashMap, HashSet};
 use bevy_egui::egui;
 use egui::Grid;
 
@@ -98,6 +99,157 @@ pub fn ui_for_reflect(value: &mut dyn Reflect, ui: &mut egui::Ui, context: &Cont
     }
 }
 
+/// Draws the inspector UI for the fields of `value` which differ from the fields of `default`.
+/// Values which aren't structs are shown completely, unless they are equal to `default`.
+pub(crate) fn ui_for_reflect_diff(
+    value: &mut dyn Reflect,
+    default: &dyn Reflect,
+    ui: &mut egui::Ui,
+    context: &Context,
+) -> bool {
+    if let (ReflectMut::Struct(value), ReflectRef::Struct(default)) =
+        (value.reflect_mut(), default.reflect_ref())
+    {
+        return ui_for_reflect_struct_diff(value, default, ui, context);
+    }
+
+    if is_default(value, default) {
+        ui.label("<default>");
+        return false;
+    }
+    ui_for_reflect(value, ui, context)
+}
+
+/// Draws the inspector UI for `value`, highlighting the struct fields which differ from `other`.
+/// Values which aren't structs are highlighted completely if they differ.
+pub(crate) fn ui_for_reflect_compare(
+    value: &mut dyn Reflect,
+    other: &dyn Reflect,
+    ui: &mut egui::Ui,
+    context: &Context,
+) -> bool {
+    if let (ReflectMut::Struct(value), ReflectRef::Struct(other)) =
+        (value.reflect_mut(), other.reflect_ref())
+    {
+        return ui_for_reflect_struct_compare(value, other, ui, context);
+    }
+
+    let differs = !is_default(value, other);
+    highlight_if(differs, ui, |ui| ui_for_reflect(value, ui, context))
+}
+
+/// The background of values which differ from the value they are compared to.
+const DIFFERENT_COLOR: egui::Color32 = egui::Color32::from_rgba_premultiplied(48, 24, 0, 48);
+
+fn highlight_if(
+    highlight: bool,
+    ui: &mut egui::Ui,
+    add_contents: impl FnOnce(&mut egui::Ui) -> bool,
+) -> bool {
+    let response = ui.vertical(add_contents);
+    if highlight {
+        ui.painter()
+            .rect_filled(response.response.rect, 2.0, DIFFERENT_COLOR);
+    }
+    response.inner
+}
+
+fn ui_for_reflect_struct_compare(
+    value: &mut dyn Struct,
+    other: &dyn Struct,
+    ui: &mut egui::Ui,
+    context: &Context,
+) -> bool {
+    let mut changed = false;
+    ui.vertical_centered(|ui| {
+        let grid = Grid::new(value.type_id());
+        grid.show(ui, |ui| {
+            for i in 0..value.field_len() {
+                let name = value.name_at(i).unwrap_or("<missing>").to_string();
+                let field = match value.field_at_mut(i) {
+                    Some(field) => field,
+                    None => continue,
+                };
+                let differs =
+                    !matches!(other.field(&name), Some(other) if is_default(field, other));
+
+                ui.label(name);
+                changed |= highlight_if(differs, ui, |ui| {
+                    ui_for_reflect(field, ui, &context.with_id(i as u64))
+                });
+                ui.end_row();
+            }
+        });
+    });
+    changed
+}
+
+fn is_default(value: &dyn Reflect, default: &dyn Reflect) -> bool {
+    value.reflect_partial_eq(default).unwrap_or(false)
+}
+
+/// The fields shown by [`ui_for_reflect_struct_diff`] while a widget is focused or dragged, keyed by their ui and context id.
+#[derive(Default)]
+struct ShownDiffFields(HashMap<egui::Id, HashSet<usize>>);
+
+fn ui_for_reflect_struct_diff(
+    value: &mut dyn Struct,
+    default: &dyn Struct,
+    ui: &mut egui::Ui,
+    context: &Context,
+) -> bool {
+    let mut shown: HashSet<usize> = (0..value.field_len())
+        .filter(|&i| {
+            let field = value.field_at(i);
+            let default = value.name_at(i).and_then(|name| default.field(name));
+            !matches!((field, default), (Some(field), Some(default)) if is_default(field, default))
+        })
+        .collect();
+
+    // fields stay visible until the interaction ends, so that dragging or typing a value
+    // through its default doesn't make the field disappear mid-edit
+    let interacting = ui.ctx().wants_keyboard_input() || ui.memory().is_anything_being_dragged();
+    if let Some(world) = unsafe { context.world() } {
+        let id = ui.id().with(context.id());
+        let mut shown_fields = world.get_resource_or_insert_with(ShownDiffFields::default);
+        match interacting {
+            true => {
+                let kept = shown_fields.0.entry(id).or_default();
+                kept.extend(shown.iter().copied());
+                shown = kept.clone();
+            }
+            false => {
+                shown_fields.0.remove(&id);
+            }
+        }
+    }
+
+    let mut changed = false;
+    ui.vertical_centered(|ui| {
+        let grid = Grid::new(value.type_id());
+        grid.show(ui, |ui| {
+            for i in 0..value.field_len() {
+                if !shown.contains(&i) {
+                    continue;
+                }
+                let name = value.name_at(i).unwrap_or("<missing>").to_string();
+                let field = match value.field_at_mut(i) {
+                    Some(field) => field,
+                    None => continue,
+                };
+
+                ui.label(name);
+                changed |= ui_for_reflect(field, ui, &context.with_id(i as u64));
+                ui.end_row();
+            }
+        });
+    });
+    if shown.is_empty() {
+        ui.label("<default>");
+    }
+    changed
+}
+
 fn ui_for_reflect_struct(value: &mut dyn Struct, ui: &mut egui::Ui, context: &Context) -> bool {
     let mut changed = false;
     ui.vertical_centered(|ui| {
@@ -105,7 +257,7 @@ fn ui_for_reflect_struct(value: &mut dyn Struct, ui: &mut egui::Ui, context: &Co
         grid.show(ui, |ui| {
             for i in 0..value.field_len() {
                 match value.name_at(i) {
-                    Some(name) => ui.label(name),
+                    Some(name) => ui.label(context.with_id(i as u64).mixed_label(name)),
                     None => ui.label("<missing>"),
                 };
                 if let Some(field) = value.field_at_mut(i) {
@@ -125,7 +277,7 @@ fn ui_for_tuple_struct(value: &mut dyn TupleStruct, ui: &mut egui::Ui, context:
     let grid = Grid::new(value.type_id());
     grid.show(ui, |ui| {
         for i in 0..value.field_len() {
-            ui.label(i.to_string());
+            ui.label(context.with_id(i as u64).mixed_label(&i.to_string()));
             if let Some(field) = value.field_mut(i) {
                 changed |= ui_for_reflect(field, ui, &context.with_id(i as u64));
             } else {
@@ -142,7 +294,7 @@ fn ui_for_tuple(value: &mut dyn Tuple, ui: &mut egui::Ui, context: &Context) ->
     let grid = Grid::new(value.type_id());
     grid.show(ui, |ui| {
         for i in 0..value.field_len() {
-            ui.label(i.to_string());
+            ui.label(context.with_id(i as u64).mixed_label(&i.to_string()));
             if let Some(field) = value.field_mut(i) {
                 changed |= ui_for_reflect(field, ui, &context.with_id(i as u64));
             } else {
@@ -213,3 +365,26 @@ fn ui_for_reflect_value(value: &mut dyn Reflect, ui: &mut egui::Ui, context: &Co
 
     false
 }
+
+/// Calls `visit` with `value` and every value nested in it,
+/// i.e. the fields of structs and tuples, the elements of lists and the keys and values of maps.
+pub(crate) fn visit_reflect(value: &dyn Reflect, visit: &mut impl FnMut(&dyn Reflect)) {
+    visit(value);
+    match value.reflect_ref() {
+        ReflectRef::Struct(value) => value
+            .iter_fields()
+            .for_each(|field| visit_reflect(field, visit)),
+        ReflectRef::TupleStruct(value) => value
+            .iter_fields()
+            .for_each(|field| visit_reflect(field, visit)),
+        ReflectRef::Tuple(value) => value
+            .iter_fields()
+            .for_each(|field| visit_reflect(field, visit)),
+        ReflectRef::List(value) => value.iter().for_each(|item| visit_reflect(item, visit)),
+        ReflectRef::Map(value) => value.iter().for_each(|(key, value)| {
+            visit_reflect(key, visit);
+            visit_reflect(value, visit);
+        }),
+        ReflectRef::Value(_) => {}
+    }
+}
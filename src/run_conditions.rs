@@ -0,0 +1,99 @@
+use bevy::prelude::*;
+use bevy_egui::egui;
+
+use crate::{Context, Inspectable};
+
+type ConditionCallback = Box<dyn Fn(&World) -> bool + Send + Sync>;
+
+/// Resource of named conditions which are evaluated every frame while inspected,
+/// showing a green or red indicator for their current result.
+///
+/// Bevy can't tell which run criteria a system has or what they returned, so the conditions are
+/// registered by hand, usually mirroring the run criteria and states your systems are gated by.
+/// ```rust,no_run
+/// use bevy::prelude::*;
+/// use bevy_inspector_egui::{InspectorPlugin, RunConditions};
+///
+/// #[derive(Debug, Clone, PartialEq, Eq, Hash)]
+/// enum AppState {
+///     Menu,
+///     InGame,
+/// }
+///
+/// fn main() {
+///     App::build()
+///         .add_plugins(DefaultPlugins)
+///         .add_state(AppState::Menu)
+///         .insert_resource(
+///             RunConditions::default()
+///                 .with("in game", |world| {
+///                     world.get_resource::<State<AppState>>().unwrap().current() == &AppState::InGame
+///                 })
+///                 .with("space held", |world| {
+///                     world.get_resource::<Input<KeyCode>>().unwrap().pressed(KeyCode::Space)
+///                 }),
+///         )
+///         .add_plugin(InspectorPlugin::<RunConditions>::new())
+///         .run();
+/// }
+/// ```
+#[derive(Default)]
+pub struct RunConditions {
+    conditions: Vec<(String, ConditionCallback)>,
+}
+
+impl RunConditions {
+    /// Show the result of `condition` as `name`, after the conditions which were added before
+    pub fn add<F>(&mut self, name: impl Into<String>, condition: F)
+    where
+        F: Fn(&World) -> bool + Send + Sync + 'static,
+    {
+        self.conditions.push((name.into(), Box::new(condition)));
+    }
+
+    /// Like [`RunConditions::add`], but takes and returns `self` for chaining
+    pub fn with<F>(mut self, name: impl Into<String>, condition: F) -> Self
+    where
+        F: Fn(&World) -> bool + Send + Sync + 'static,
+    {
+        self.add(name, condition);
+        self
+    }
+}
+
+impl Inspectable for RunConditions {
+    type Attributes = ();
+
+    fn ui(&mut self, ui: &mut egui::Ui, _: Self::Attributes, context: &Context) -> bool {
+        let world = expect_world!(ui, context, "RunConditions");
+
+        if self.conditions.is_empty() {
+            ui.label("No run conditions have been added");
+            return false;
+        }
+
+        egui::Grid::new(context.id()).show(ui, |ui| {
+            for (name, condition) in &self.conditions {
+                let result = condition(world);
+                ui.horizontal(|ui| {
+                    indicator(ui, result);
+                    ui.label(name);
+                });
+                ui.label(result.to_string());
+                ui.end_row();
+            }
+        });
+        false
+    }
+}
+
+/// A green or red dot, depending on `value`.
+fn indicator(ui: &mut egui::Ui, value: bool) {
+    let size = ui.spacing().interact_size.y * 0.5;
+    let (rect, _) = ui.allocate_exact_size(egui::vec2(size, size), egui::Sense::hover());
+    let color = match value {
+        true => egui::Color32::from_rgb(0, 160, 0),
+        false => egui::Color32::from_rgb(180, 0, 0),
+    };
+    ui.painter().circle_filled(rect.center(), size / 2.0, color);
+}
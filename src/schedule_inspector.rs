@@ -0,0 +1,147 @@
+use bevy::{
+    ecs::schedule::{Stage, StageLabel, SystemContainer},
+    prelude::*,
+};
+use bevy_egui::{egui, EguiContext, EguiPlugin};
+use pretty_type_name::pretty_type_name_str;
+
+/// Plugin for displaying a read-only window listing the stages of the app's schedule and their systems.
+///
+/// The app's schedule can't be accessed while it is running, so it is recorded when this plugin is built.
+/// Only stages and systems which were added before `ScheduleInspectorPlugin` will be shown,
+/// so it should be the last plugin you add.
+/// ```rust,no_run
+/// use bevy::prelude::*;
+/// use bevy_inspector_egui::ScheduleInspectorPlugin;
+///
+/// fn main() {
+///     App::build()
+///         .add_plugins(DefaultPlugins)
+///         .add_system(your_system.system())
+///         .add_plugin(ScheduleInspectorPlugin)
+///         .run();
+/// }
+/// # fn your_system() {}
+/// ```
+#[derive(Debug, Default)]
+pub struct ScheduleInspectorPlugin;
+
+impl Plugin for ScheduleInspectorPlugin {
+    fn build(&self, app: &mut AppBuilder) {
+        if !app.world().contains_resource::<EguiContext>() {
+            app.add_plugin(EguiPlugin);
+        }
+
+        let stages = stage_infos(&app.app.schedule);
+        app.insert_resource(ScheduleInfo { stages });
+
+        app.add_system(schedule_inspector_ui.system());
+    }
+}
+
+struct ScheduleInfo {
+    stages: Vec<StageInfo>,
+}
+
+struct StageInfo {
+    label: String,
+    kind: StageKind,
+}
+
+enum StageKind {
+    Systems {
+        exclusive_at_start: Vec<String>,
+        parallel: Vec<String>,
+        exclusive_before_commands: Vec<String>,
+        exclusive_at_end: Vec<String>,
+    },
+    Schedule(Vec<StageInfo>),
+    /// custom [`Stage`] implementations can't be inspected
+    Unknown,
+}
+
+fn stage_infos(schedule: &Schedule) -> Vec<StageInfo> {
+    schedule
+        .iter_stages()
+        .map(|(label, stage)| StageInfo {
+            label: stage_label_name(label),
+            kind: stage_kind(stage),
+        })
+        .collect()
+}
+
+fn stage_kind(stage: &dyn Stage) -> StageKind {
+    if let Some(stage) = stage.downcast_ref::<SystemStage>() {
+        StageKind::Systems {
+            exclusive_at_start: system_names(stage.exclusive_at_start_systems()),
+            parallel: system_names(stage.parallel_systems()),
+            exclusive_before_commands: system_names(stage.exclusive_before_commands_systems()),
+            exclusive_at_end: system_names(stage.exclusive_at_end_systems()),
+        }
+    } else if let Some(schedule) = stage.downcast_ref::<Schedule>() {
+        StageKind::Schedule(stage_infos(schedule))
+    } else {
+        StageKind::Unknown
+    }
+}
+
+fn stage_label_name(label: &dyn StageLabel) -> String {
+    format!("{:?}", label)
+}
+
+fn system_names(systems: &[impl SystemContainer]) -> Vec<String> {
+    systems
+        .iter()
+        .map(|system| pretty_type_name_str(&system.name()))
+        .collect()
+}
+
+fn schedule_inspector_ui(egui_context: Res<EguiContext>, schedule_info: Res<ScheduleInfo>) {
+    egui::Window::new("Schedule")
+        .scroll(true)
+        .show(egui_context.ctx(), |ui| {
+            crate::plugin::default_settings(ui);
+            stages_ui(ui, &schedule_info.stages);
+        });
+}
+
+fn stages_ui(ui: &mut egui::Ui, stages: &[StageInfo]) {
+    if stages.is_empty() {
+        ui.label("No stages");
+    }
+
+    for stage in stages {
+        egui::CollapsingHeader::new(&stage.label)
+            .id_source(ui.id().with(&stage.label))
+            .show(ui, |ui| match &stage.kind {
+                StageKind::Systems {
+                    exclusive_at_start,
+                    parallel,
+                    exclusive_before_commands,
+                    exclusive_at_end,
+                } => {
+                    systems_ui(ui, "Exclusive (at start)", exclusive_at_start);
+                    systems_ui(ui, "Parallel", parallel);
+                    systems_ui(ui, "Exclusive (before commands)", exclusive_before_commands);
+                    systems_ui(ui, "Exclusive (at end)", exclusive_at_end);
+                }
+                StageKind::Schedule(stages) => stages_ui(ui, stages),
+                StageKind::Unknown => {
+                    ui.label("Custom stages can't be inspected");
+                }
+            });
+    }
+}
+
+fn systems_ui(ui: &mut egui::Ui, title: &str, systems: &[String]) {
+    if systems.is_empty() {
+        return;
+    }
+
+    ui.label(title);
+    ui.indent(title, |ui| {
+        for system in systems {
+            ui.label(system);
+        }
+    });
+}
@@ -28,6 +28,12 @@ macro_rules! impl_for_struct_delegate_fields {
             type Attributes = ();
 
             fn ui(&mut self, ui: &mut $crate::egui::Ui, _: Self::Attributes, context: &$crate::Context) -> bool {
+                if context.max_depth_reached() {
+                    ui.label("(max depth reached)");
+                    return false;
+                }
+                let context = &context.nested();
+
                 let mut changed = false;
 
                 ui.vertical_centered(|ui| {
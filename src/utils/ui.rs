@@ -34,3 +34,25 @@ pub fn label_button(ui: &mut egui::Ui, text: &str, text_color: egui::Color32) ->
     ui.add(egui::Button::new(text).text_color(text_color).frame(false))
         .clicked()
 }
+
+/// Shows the widget for `value` followed by a button copying it to the clipboard,
+/// if enabled by [`Context::copy_buttons`](crate::Context::copy_buttons).
+pub fn with_copy_button<T: std::fmt::Display, R>(
+    ui: &mut egui::Ui,
+    context: &crate::Context,
+    value: &mut T,
+    add_contents: impl FnOnce(&mut egui::Ui, &mut T) -> R,
+) -> R {
+    if !context.copy_buttons() {
+        return add_contents(ui, value);
+    }
+
+    ui.horizontal(|ui| {
+        let result = add_contents(ui, value);
+        if label_button(ui, "📋", egui::Color32::GRAY) {
+            ui.output().copied_text = value.to_string();
+        }
+        result
+    })
+    .inner
+}
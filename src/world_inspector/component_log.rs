@@ -0,0 +1,119 @@
+use bevy::{ecs::component::ComponentId, prelude::*, reflect::TypeRegistryArc};
+use pretty_type_name::pretty_type_name_str;
+use std::collections::VecDeque;
+
+use super::{
+    get_component_and_ticks,
+    snapshot::{for_each_archetype_component, reflect_component},
+    WorldInspectorParams,
+};
+use crate::InspectableRegistry;
+
+/// An entry of the [`ComponentLog`]
+pub(crate) struct ComponentLogEntry {
+    pub(crate) frame: u64,
+    pub(crate) entity: Entity,
+    pub(crate) component: String,
+    pub(crate) added: bool,
+}
+
+/// The components which were recently added to or removed from an entity,
+/// see [`WorldInspectorParams::show_component_log`].
+/// Only the newest [`ComponentLog::CAPACITY`] entries are kept.
+#[derive(Default)]
+pub(crate) struct ComponentLog {
+    pub(crate) entries: VecDeque<ComponentLogEntry>,
+    frame: u64,
+    last_change_tick: Option<u32>,
+}
+
+impl ComponentLog {
+    const CAPACITY: usize = 200;
+
+    fn push(&mut self, entity: Entity, component: String, added: bool) {
+        if self.entries.len() == Self::CAPACITY {
+            self.entries.pop_front();
+        }
+        self.entries.push_back(ComponentLogEntry {
+            frame: self.frame,
+            entity,
+            component,
+            added,
+        });
+    }
+}
+
+/// Records the additions and removals of registered components which aren't ignored.
+///
+/// Removed components are only tracked until the end of the frame,
+/// so this runs at the end of [`CoreStage::PostUpdate`].
+pub(crate) fn record_component_log(world: &mut World) {
+    let params = world.get_resource::<WorldInspectorParams>().unwrap();
+    if !params.show_component_log {
+        world.remove_resource::<ComponentLog>();
+        return;
+    }
+
+    let component_ids = registered_components(world, params);
+    let change_tick = world.read_change_tick();
+
+    let mut log = world.remove_resource::<ComponentLog>().unwrap_or_default();
+    log.frame += 1;
+
+    // everything would count as added on the first frame
+    if let Some(last_change_tick) = log.last_change_tick {
+        let name = |component_id| {
+            pretty_type_name_str(world.components().get_info(component_id).unwrap().name())
+        };
+
+        for_each_archetype_component(world, |archetype, component_id, _| {
+            if !component_ids.contains(&component_id) {
+                return;
+            }
+            for &entity in archetype.entities() {
+                let location = world.entities().get(entity).unwrap();
+                // SAFE: the ticks are only read while no component is borrowed mutably
+                let ticks =
+                    unsafe { get_component_and_ticks(world, component_id, entity, location) };
+                if let Some((_, ticks)) = ticks {
+                    if unsafe { &*ticks }.is_added(last_change_tick, change_tick) {
+                        log.push(entity, name(component_id), true);
+                    }
+                }
+            }
+        });
+
+        for &component_id in &component_ids {
+            for entity in world.removed_with_id(component_id) {
+                log.push(entity, name(component_id), false);
+            }
+        }
+    }
+    log.last_change_tick = Some(change_tick);
+
+    world.insert_resource(log);
+}
+
+/// The components which can be displayed by the world inspector and aren't ignored.
+fn registered_components(world: &World, params: &WorldInspectorParams) -> Vec<ComponentId> {
+    let inspectable_registry = world.get_resource::<InspectableRegistry>();
+    let type_registry = world.get_resource::<TypeRegistryArc>();
+    let type_registry = type_registry.map(|type_registry| type_registry.internal.read());
+
+    (0..world.components().len())
+        .map(ComponentId::new)
+        .filter(|&component_id| {
+            let type_id = match world
+                .components()
+                .get_info(component_id)
+                .and_then(|info| info.type_id())
+            {
+                Some(type_id) if !params.should_ignore_component(type_id) => type_id,
+                _ => return false,
+            };
+            let inspectable = matches!(inspectable_registry, Some(registry) if registry.impls.contains_key(&type_id));
+            let reflectable = matches!(&type_registry, Some(registry) if reflect_component(registry, type_id).is_some());
+            inspectable || reflectable
+        })
+        .collect()
+}
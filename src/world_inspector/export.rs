@@ -0,0 +1,87 @@
+use bevy::{
+    log::{info, warn},
+    prelude::*,
+    reflect::TypeRegistryArc,
+    scene::Entity as SceneEntity,
+};
+use pretty_type_name::pretty_type_name_str;
+
+use super::{
+    snapshot::{is_hierarchy_component, reflect_component},
+    WorldInspectorParams,
+};
+
+/// Serializes the components of `entities` into a RON scene.
+/// Components which aren't registered with `#[reflect(Component)]` are skipped with a warning.
+pub(crate) fn export_scene(
+    world: &World,
+    entities: &[Entity],
+    params: &WorldInspectorParams,
+) -> Result<String, String> {
+    let type_registry = world.get_resource::<TypeRegistryArc>().unwrap().clone();
+
+    let mut scene = DynamicScene::default();
+    {
+        let type_registry = type_registry.internal.read();
+        for &entity in entities {
+            let entity_ref = match world.get_entity(entity) {
+                Some(entity_ref) => entity_ref,
+                None => continue,
+            };
+            let archetype = entity_ref.archetype();
+
+            let mut components = Vec::new();
+            for component_id in archetype.components() {
+                let component_info = world.components().get_info(component_id).unwrap();
+                let type_id = match component_info.type_id() {
+                    Some(type_id)
+                        if !params.should_ignore_component(type_id)
+                            && !is_hierarchy_component(type_id) =>
+                    {
+                        type_id
+                    }
+                    _ => continue,
+                };
+                let reflect_component = match reflect_component(&type_registry, type_id) {
+                    Some(reflect_component) => reflect_component,
+                    None => {
+                        warn!(
+                            "skipping `{}` in scene export, it is not registered with `#[reflect(Component)]`",
+                            pretty_type_name_str(component_info.name())
+                        );
+                        continue;
+                    }
+                };
+
+                if let Some(value) = reflect_component.reflect_component(world, entity) {
+                    components.push(value.clone_value());
+                }
+            }
+
+            scene.entities.push(SceneEntity {
+                entity: entity.id(),
+                components,
+            });
+        }
+    }
+
+    scene
+        .serialize_ron(&type_registry)
+        .map_err(|error| error.to_string())
+}
+
+/// Writes the exported scene to [`WorldInspectorParams::export_path`] if set,
+/// or copies it to the clipboard otherwise.
+pub(crate) fn save_scene(
+    ui: &mut bevy_egui::egui::Ui,
+    scene: String,
+    params: &WorldInspectorParams,
+) {
+    match &params.export_path {
+        Some(path) => match std::fs::write(path, scene) {
+            Ok(()) => info!("exported scene to {}", path.display()),
+            Err(error) => warn!("failed to export scene to {}: {}", path.display(), error),
+        },
+        None => ui.output().copied_text = scene,
+    }
+}
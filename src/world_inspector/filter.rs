@@ -0,0 +1,59 @@
+use bevy::ecs::world::EntityRef;
+
+type FilterCallback = Box<dyn Fn(&EntityRef) -> bool + Send + Sync>;
+
+/// Resource for filtering the entities shown by the [`WorldInspectorPlugin`](crate::WorldInspectorPlugin) at runtime.
+///
+/// In contrast to [`WorldInspectorPlugin::filter`](crate::WorldInspectorPlugin::filter), which filters using a
+/// [`WorldQuery`](bevy::ecs::query::WorldQuery), the predicate can look at the values of the components.
+/// It is applied in addition to the query filter and gets evaluated every frame for every listed entity.
+/// ```rust,no_run
+/// # use bevy::prelude::*;
+/// # use bevy_inspector_egui::{WorldInspectorFilter, WorldInspectorPlugin};
+/// struct Health {
+///     current: u32,
+/// }
+///
+/// App::build()
+///     .insert_resource(WorldInspectorFilter::new(|entity| {
+///         matches!(entity.get::<Health>(), Some(health) if health.current == 0)
+///     }))
+///     .add_plugin(WorldInspectorPlugin::new())
+///     .run();
+/// ```
+#[derive(Default)]
+pub struct WorldInspectorFilter {
+    predicate: Option<FilterCallback>,
+}
+
+impl WorldInspectorFilter {
+    /// Create a filter which only shows entities matching `predicate`
+    pub fn new<F>(predicate: F) -> Self
+    where
+        F: Fn(&EntityRef) -> bool + Send + Sync + 'static,
+    {
+        WorldInspectorFilter {
+            predicate: Some(Box::new(predicate)),
+        }
+    }
+
+    /// Only show entities matching `predicate`, replacing the previous predicate
+    pub fn set<F>(&mut self, predicate: F)
+    where
+        F: Fn(&EntityRef) -> bool + Send + Sync + 'static,
+    {
+        self.predicate = Some(Box::new(predicate));
+    }
+
+    /// Remove the predicate, showing all entities again
+    pub fn clear(&mut self) {
+        self.predicate = None;
+    }
+
+    pub(crate) fn matches(&self, entity: &EntityRef) -> bool {
+        match &self.predicate {
+            Some(predicate) => predicate(entity),
+            None => true,
+        }
+    }
+}
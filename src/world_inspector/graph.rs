@@ -0,0 +1,234 @@
+use bevy::{
+    prelude::*,
+    reflect::{TypeRegistryArc, TypeRegistryInternal},
+    utils::HashMap,
+};
+use bevy_egui::egui::{self, Color32};
+use std::collections::BTreeMap;
+
+use super::{
+    snapshot::{is_hierarchy_component, reflect_component},
+    WorldInspectorParams,
+};
+use crate::reflect::visit_reflect;
+
+#[derive(Clone, Copy, PartialEq)]
+enum Edge {
+    /// From a parent to its child
+    Hierarchy,
+    /// From an entity to one referenced in one of its components
+    Reference,
+}
+
+/// The selected entities together with their parents, children and the entities their components reference,
+/// see [`WorldInspectorParams::show_relationship_graph`].
+/// The nodes are laid out in rows by their depth in the hierarchy.
+pub(crate) struct RelationshipGraph {
+    nodes: Vec<(Entity, usize)>,
+    edges: Vec<(Entity, Entity, Edge)>,
+}
+
+impl RelationshipGraph {
+    pub(crate) fn new(world: &World, selected: &[Entity], params: &WorldInspectorParams) -> Self {
+        let type_registry = world.get_resource::<TypeRegistryArc>().unwrap().clone();
+        let type_registry = type_registry.internal.read();
+
+        let mut entities = Vec::new();
+        let mut edges = Vec::new();
+        let mut add_edge = |entities: &mut Vec<Entity>, from: Entity, to: Entity, edge: Edge| {
+            if world.get_entity(to).is_none() {
+                return;
+            }
+            for &entity in &[from, to] {
+                if !entities.contains(&entity) {
+                    entities.push(entity);
+                }
+            }
+            if !edges.contains(&(from, to, edge)) {
+                edges.push((from, to, edge));
+            }
+        };
+
+        for &entity in selected {
+            if !entities.contains(&entity) {
+                entities.push(entity);
+            }
+            if let Some(parent) = world.get::<Parent>(entity) {
+                add_edge(&mut entities, parent.0, entity, Edge::Hierarchy);
+            }
+            if let Some(children) = world.get::<Children>(entity) {
+                for &child in children.iter() {
+                    add_edge(&mut entities, entity, child, Edge::Hierarchy);
+                }
+            }
+            for target in referenced_entities(world, &type_registry, entity, params) {
+                if target != entity {
+                    add_edge(&mut entities, entity, target, Edge::Reference);
+                }
+            }
+        }
+
+        let nodes = entities
+            .into_iter()
+            .map(|entity| (entity, hierarchy_depth(world, entity)))
+            .collect();
+        RelationshipGraph { nodes, edges }
+    }
+
+    /// Draws the graph and returns the entity whose node was clicked.
+    pub(crate) fn ui(
+        &self,
+        ui: &mut egui::Ui,
+        name: impl Fn(Entity) -> String,
+        selected: impl Fn(Entity) -> bool,
+    ) -> Option<Entity> {
+        const MAX_NODE_WIDTH: f32 = 110.0;
+        const MIN_NODE_WIDTH: f32 = 40.0;
+        const NODE_HEIGHT: f32 = 22.0;
+        const SPACING: f32 = 8.0;
+        const ROW_HEIGHT: f32 = 48.0;
+        const MAX_NAME_LEN: usize = 14;
+
+        if self.nodes.is_empty() {
+            ui.label("No entities to show");
+            return None;
+        }
+
+        let mut rows: BTreeMap<usize, Vec<Entity>> = BTreeMap::new();
+        for &(entity, depth) in &self.nodes {
+            rows.entry(depth).or_default().push(entity);
+        }
+        for row in rows.values_mut() {
+            row.sort();
+        }
+
+        let widest = rows.values().map(Vec::len).max().unwrap_or(1) as f32;
+        let available_width = ui.available_width();
+        let node_width = ((available_width - SPACING * (widest - 1.0)) / widest)
+            .clamp(MIN_NODE_WIDTH, MAX_NODE_WIDTH);
+        let width = (widest * (node_width + SPACING) - SPACING).max(available_width);
+        let height = rows.len() as f32 * ROW_HEIGHT - (ROW_HEIGHT - NODE_HEIGHT);
+        let (rect, _) = ui.allocate_exact_size(egui::vec2(width, height), egui::Sense::hover());
+
+        let mut node_rects = HashMap::default();
+        for (row_index, row) in rows.values().enumerate() {
+            let row_width = row.len() as f32 * (node_width + SPACING) - SPACING;
+            let left = rect.center().x - row_width / 2.0;
+            let top = rect.top() + row_index as f32 * ROW_HEIGHT;
+            for (i, &entity) in row.iter().enumerate() {
+                let min = egui::pos2(left + i as f32 * (node_width + SPACING), top);
+                let node_rect = egui::Rect::from_min_size(min, egui::vec2(node_width, NODE_HEIGHT));
+                node_rects.insert(entity, node_rect);
+            }
+        }
+
+        let painter = ui.painter();
+        for &(from, to, edge) in &self.edges {
+            let (from, to) = match (node_rects.get(&from), node_rects.get(&to)) {
+                (Some(from), Some(to)) => (from.center(), to.center()),
+                _ => continue,
+            };
+            let color = match edge {
+                Edge::Hierarchy => Color32::GRAY,
+                Edge::Reference => Color32::LIGHT_BLUE,
+            };
+            painter.line_segment([from, to], (1.0, color));
+            if edge == Edge::Reference {
+                // mark the referenced end, which may be in the same row
+                let direction = (from - to).normalized();
+                let offset = NODE_HEIGHT / 2.0 + 3.0;
+                painter.circle_filled(to + direction * offset, 3.0, color);
+            }
+        }
+
+        let mut clicked = None;
+        for &(entity, _) in &self.nodes {
+            let node_rect = node_rects[&entity];
+            let id = ui.id().with("relationship graph").with(entity);
+            let response = ui.interact(node_rect, id, egui::Sense::click());
+
+            let visuals = ui.style().interact(&response);
+            let stroke = match selected(entity) {
+                true => egui::Stroke::new(1.5, Color32::LIGHT_BLUE),
+                false => visuals.bg_stroke,
+            };
+            ui.painter().rect(node_rect, 3.0, visuals.bg_fill, stroke);
+
+            let full_name = name(entity);
+            let short_name = match full_name.chars().count() > MAX_NAME_LEN {
+                true => {
+                    let name: String = full_name.chars().take(MAX_NAME_LEN - 1).collect();
+                    format!("{}…", name)
+                }
+                false => full_name.clone(),
+            };
+            ui.painter_at(node_rect).text(
+                node_rect.center(),
+                egui::Align2::CENTER_CENTER,
+                short_name,
+                egui::TextStyle::Small,
+                visuals.text_color(),
+            );
+
+            if response.clicked() {
+                clicked = Some(entity);
+            }
+            response.on_hover_text(full_name);
+        }
+        clicked
+    }
+}
+
+fn hierarchy_depth(world: &World, entity: Entity) -> usize {
+    let mut depth = 0;
+    let mut current = entity;
+    while let Some(parent) = world.get::<Parent>(current) {
+        current = parent.0;
+        depth += 1;
+    }
+    depth
+}
+
+/// The entities stored in the reflectable components of `entity`, apart from the hierarchy components.
+fn referenced_entities(
+    world: &World,
+    type_registry: &TypeRegistryInternal,
+    entity: Entity,
+    params: &WorldInspectorParams,
+) -> Vec<Entity> {
+    let mut entities = Vec::new();
+    let entity_ref = match world.get_entity(entity) {
+        Some(entity_ref) => entity_ref,
+        None => return entities,
+    };
+    for component_id in entity_ref.archetype().components() {
+        let type_id = match world
+            .components()
+            .get_info(component_id)
+            .and_then(|info| info.type_id())
+        {
+            Some(type_id) if !is_hierarchy_component(type_id) => type_id,
+            _ => continue,
+        };
+        if params.should_ignore_component(type_id) {
+            continue;
+        }
+        let reflect_component = match reflect_component(type_registry, type_id) {
+            Some(reflect_component) => reflect_component,
+            None => continue,
+        };
+        if let Some(value) = reflect_component.reflect_component(world, entity) {
+            collect_entities(value, &mut entities);
+        }
+    }
+    entities
+}
+
+/// Pushes every `Entity` nested in `value`.
+fn collect_entities(value: &dyn Reflect, entities: &mut Vec<Entity>) {
+    visit_reflect(value, &mut |value| {
+        if let Some(&entity) = value.downcast_ref::<Entity>() {
+            entities.push(entity);
+        }
+    });
+}
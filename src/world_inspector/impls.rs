@@ -1,10 +1,10 @@
-use super::{WorldInspectorParams, WorldUIContext};
-use crate::{utils, Inspectable};
+use super::{PendingJump, WorldInspectorParams, WorldUIContext};
+use crate::{utils, utils::ui::label_button, Inspectable};
 use bevy::{
     ecs::query::{Fetch, FilterFetch, WorldQuery},
     prelude::*,
 };
-use bevy_egui::egui::CollapsingHeader;
+use bevy_egui::egui::{self, CollapsingHeader, Color32};
 use std::marker::PhantomData;
 use utils::error_label;
 
@@ -48,14 +48,23 @@ impl Inspectable for Entity {
             world.get_resource_or_insert_with(WorldInspectorParams::default);
         let params = std::mem::replace(&mut *world_inspector_params, WorldInspectorParams::empty());
 
+        let mut jump_to_entity = false;
+
         let world_ui_ctx = WorldUIContext::new(context.ui_ctx, world);
         let changed = ui
-            .vertical(|ui| world_ui_ctx.entity_ui_inner(ui, *self, &params, context.id(), &options))
+            .vertical(|ui| {
+                jump_to_entity = label_button(ui, "➡ Select", Color32::LIGHT_BLUE);
+                world_ui_ctx.entity_ui_inner(ui, *self, &params, context.id(), &options)
+            })
             .inner;
         drop(world_ui_ctx);
 
         *world.get_resource_mut::<WorldInspectorParams>().unwrap() = params;
 
+        if jump_to_entity {
+            world.insert_resource(PendingJump(*self));
+        }
+
         changed
     }
 }
@@ -220,3 +229,189 @@ where
         changed
     }
 }
+
+/// A list of entities, displayed as links to the entities which select them in the world inspector.
+///
+/// Entities can't be used in a `Vec<T>` field, because they have no default value to add,
+/// so use this type for components referring to a list of other entities.
+/// New entries start out as an entity id which is never spawned, showing as `(dead)`, and can be retargeted using the combo box next to them.
+///
+/// ```rust,no_run
+/// use bevy::prelude::*;
+/// use bevy_inspector_egui::{Inspectable, widgets::EntityList};
+///
+/// #[derive(Inspectable, Default)]
+/// struct Friends {
+///     friends: EntityList,
+/// }
+/// ```
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct EntityList(pub Vec<Entity>);
+
+impl std::ops::Deref for EntityList {
+    type Target = Vec<Entity>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+impl std::ops::DerefMut for EntityList {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+impl From<Vec<Entity>> for EntityList {
+    fn from(entities: Vec<Entity>) -> Self {
+        EntityList(entities)
+    }
+}
+
+impl Inspectable for EntityList {
+    type Attributes = ();
+
+    fn ui(
+        &mut self,
+        ui: &mut bevy_egui::egui::Ui,
+        _: Self::Attributes,
+        context: &crate::Context,
+    ) -> bool {
+        let world = expect_world!(ui, context, "EntityList");
+
+        let mut changed = false;
+        let mut to_delete = None;
+        let mut jump_to = None;
+
+        ui.vertical(|ui| {
+            for (i, entity) in self.0.iter_mut().enumerate() {
+                ui.horizontal(|ui| {
+                    if label_button(ui, "✖", Color32::RED) {
+                        to_delete = Some(i);
+                    }
+
+                    if entity_link(ui, world, *entity) {
+                        jump_to = Some(*entity);
+                    }
+
+                    egui::ComboBox::from_id_source(context.id().with(i))
+                        .selected_text("Retarget")
+                        .show_ui(ui, |ui| {
+                            // only listed while the combo box is open, since it goes through every entity
+                            for (name, candidate) in &retarget_candidates(world) {
+                                if ui.selectable_label(candidate == entity, name).clicked() {
+                                    changed |= candidate != entity;
+                                    *entity = *candidate;
+                                }
+                            }
+                        });
+                });
+            }
+
+            ui.vertical_centered_justified(|ui| {
+                if ui.button("+").clicked() {
+                    self.0.push(null_entity());
+                    changed = true;
+                }
+            });
+        });
+
+        if let Some(i) = to_delete {
+            self.0.remove(i);
+            changed = true;
+        }
+
+        if let Some(entity) = jump_to {
+            world.insert_resource(PendingJump(entity));
+        }
+
+        changed
+    }
+}
+
+/// The entity added by the "+" button of an [`EntityList`], until it is retargeted.
+/// Entity ids are allocated from zero upwards, so this one is never alive and shows as `(dead)`.
+fn null_entity() -> Entity {
+    Entity::new(u32::MAX)
+}
+
+/// Every entity of the world with its name, sorted by name.
+fn retarget_candidates(world: &World) -> Vec<(String, Entity)> {
+    let mut entities: Vec<_> = world
+        .archetypes()
+        .iter()
+        .flat_map(|archetype| archetype.entities().iter().copied())
+        .map(|entity| {
+            (
+                super::guess_entity_name(world.entity(entity)).into_owned(),
+                entity,
+            )
+        })
+        .collect();
+    entities.sort();
+    entities
+}
+
+/// Read-only link to the parent entity, which selects it in the world inspector.
+impl Inspectable for Parent {
+    type Attributes = ();
+
+    fn ui(
+        &mut self,
+        ui: &mut bevy_egui::egui::Ui,
+        _: Self::Attributes,
+        context: &crate::Context,
+    ) -> bool {
+        let world = expect_world!(ui, context, "Parent");
+
+        if entity_link(ui, world, self.0) {
+            world.insert_resource(PendingJump(self.0));
+        }
+        false
+    }
+}
+
+/// Read-only list of links to the child entities, which select them in the world inspector.
+impl Inspectable for Children {
+    type Attributes = ();
+
+    fn ui(
+        &mut self,
+        ui: &mut bevy_egui::egui::Ui,
+        _: Self::Attributes,
+        context: &crate::Context,
+    ) -> bool {
+        let world = expect_world!(ui, context, "Children");
+
+        if self.is_empty() {
+            ui.label("No children");
+            return false;
+        }
+
+        let mut jump_to = None;
+        ui.vertical(|ui| {
+            for &child in self.iter() {
+                if entity_link(ui, world, child) {
+                    jump_to = Some(child);
+                }
+            }
+        });
+
+        if let Some(entity) = jump_to {
+            world.insert_resource(PendingJump(entity));
+        }
+        false
+    }
+}
+
+/// Shows the name of `entity` as a link, or `(dead)` if it doesn't exist. Returns whether the link was clicked.
+fn entity_link(ui: &mut egui::Ui, world: &World, entity: Entity) -> bool {
+    match world.get_entity(entity) {
+        Some(entity_ref) => {
+            let name = super::guess_entity_name(entity_ref);
+            label_button(ui, &name, Color32::LIGHT_BLUE)
+        }
+        None => {
+            ui.label("(dead)");
+            false
+        }
+    }
+}
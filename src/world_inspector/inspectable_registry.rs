@@ -1,12 +1,39 @@
 use crate::{Context, Inspectable};
 use bevy::render::camera::{DepthCalculation, ScalingMode, VisibleEntities, WindowOrigin};
-use bevy::{pbr::AmbientLight, prelude::*};
+use bevy::{pbr::AmbientLight, prelude::*, sprite::SpriteResizeMode};
 use bevy::{render::pipeline::PrimitiveTopology, utils::HashMap};
 use bevy_egui::egui;
-use std::any::TypeId;
+use serde::{de::DeserializeOwned, Serialize};
+use std::{any::TypeId, path::PathBuf};
 
 pub(crate) type InspectCallback =
     Box<dyn Fn(*mut u8, &mut egui::Ui, &Context) -> bool + Send + Sync>;
+pub(crate) type DefaultCallback = Box<dyn Fn() -> Box<dyn Reflect> + Send + Sync>;
+type SerializeCallback =
+    Box<dyn Fn(&dyn std::any::Any) -> Result<String, ron::Error> + Send + Sync>;
+type DeserializeCallback =
+    Box<dyn Fn(&mut dyn std::any::Any, &str) -> Result<(), ron::Error> + Send + Sync>;
+
+/// The file a resource is saved to and reloaded from, see [`InspectableRegistry::register_resource_file`].
+pub(crate) struct ResourceFile {
+    pub(crate) path: PathBuf,
+    serialize: SerializeCallback,
+    deserialize: DeserializeCallback,
+}
+
+impl ResourceFile {
+    /// Writes `value` to the file as RON.
+    pub(crate) fn save(&self, value: &dyn std::any::Any) -> Result<(), String> {
+        let text = (self.serialize)(value).map_err(|error| error.to_string())?;
+        std::fs::write(&self.path, text).map_err(|error| error.to_string())
+    }
+
+    /// Replaces `value` with the contents of the file.
+    pub(crate) fn reload(&self, value: &mut dyn std::any::Any) -> Result<(), String> {
+        let text = std::fs::read_to_string(&self.path).map_err(|error| error.to_string())?;
+        (self.deserialize)(value, &text).map_err(|error| error.to_string())
+    }
+}
 
 macro_rules! register {
     ($this:ident $($ty:ty),* $(,)?) => {
@@ -14,10 +41,31 @@ macro_rules! register {
     };
 }
 
+/// A registration of one or more types in the [`InspectableRegistry`].
+///
+/// Unlike [`Inspectable`] this trait is object safe, so the registrations of many different types can be
+/// collected in a single list and passed to [`InspectableRegistry::register_all`].
+/// It is implemented for every `Fn(&mut InspectableRegistry)`, including the registry's own methods
+/// like `InspectableRegistry::register::<T>`.
+pub trait InspectableRegistration {
+    /// Registers the types in `registry`
+    fn register(&self, registry: &mut InspectableRegistry);
+}
+
+impl<F: Fn(&mut InspectableRegistry)> InspectableRegistration for F {
+    fn register(&self, registry: &mut InspectableRegistry) {
+        self(registry)
+    }
+}
+
 /// The `InspectableRegistry` can be used to tell the [`WorldInspectorPlugin`](crate::WorldInspectorPlugin)
 /// how to display a type.
 pub struct InspectableRegistry {
     pub(crate) impls: HashMap<TypeId, InspectCallback>,
+    pub(crate) defaults: HashMap<TypeId, DefaultCallback>,
+    pub(crate) state_variants: HashMap<TypeId, Box<dyn std::any::Any + Send + Sync>>,
+    pub(crate) annotations: HashMap<TypeId, String>,
+    pub(crate) resource_files: HashMap<TypeId, ResourceFile>,
 }
 
 impl InspectableRegistry {
@@ -41,6 +89,156 @@ impl InspectableRegistry {
         self.impls.insert(type_id, callback);
     }
 
+    /// Registers a read-only display for `T`, which shows the string returned by `f`.
+    /// This is lighter than a full widget using [`InspectableRegistry::register_raw`].
+    /// ```rust,no_run
+    /// # use bevy_inspector_egui::InspectableRegistry;
+    /// struct Score(u32, u32);
+    ///
+    /// let mut registry = InspectableRegistry::default();
+    /// registry.register_display(|score: &Score| format!("{} : {}", score.0, score.1));
+    /// ```
+    pub fn register_display<T: 'static, F>(&mut self, f: F)
+    where
+        F: Fn(&T) -> String + Send + Sync + 'static,
+    {
+        self.register_raw(move |value: &mut T, ui: &mut egui::Ui, _: &Context| {
+            ui.label(f(value));
+            false
+        });
+    }
+
+    /// Register the default value of `T`, so that the [`WorldInspectorPlugin`](crate::WorldInspectorPlugin)
+    /// can hide the fields which are at their default value.
+    /// See [`WorldInspectorParams::diff_from_default`](crate::WorldInspectorParams::diff_from_default).
+    ///
+    /// The fields are compared using their `Reflect` implementation, and the component
+    /// needs to be registered with `#[reflect(Component)]`.
+    pub fn register_default<T: Reflect + Default>(&mut self) {
+        self.defaults
+            .insert(TypeId::of::<T>(), Box::new(|| Box::new(T::default())));
+    }
+
+    /// Register the states `State<T>` can be switched to from the inspector.
+    /// Since the states can't be enumerated generically, `State<T>` is displayed read-only otherwise.
+    /// ```rust,no_run
+    /// # use bevy::prelude::*;
+    /// # use bevy_inspector_egui::{InspectableRegistry, InspectorPlugin};
+    /// #[derive(Debug, Clone, PartialEq, Eq, Hash)]
+    /// enum AppState {
+    ///     Menu,
+    ///     InGame,
+    /// }
+    ///
+    /// App::build()
+    ///     .insert_resource(State::new(AppState::Menu))
+    ///     .insert_resource(
+    ///         InspectableRegistry::default().with_state(vec![AppState::Menu, AppState::InGame]),
+    ///     )
+    ///     .add_plugin(InspectorPlugin::<State<AppState>>::new_insert_manually())
+    ///     .run();
+    /// ```
+    pub fn register_state<T: Send + Sync + 'static>(&mut self, variants: Vec<T>) {
+        self.state_variants
+            .insert(TypeId::of::<T>(), Box::new(variants));
+    }
+
+    /// Variant of [`InspectableRegistry::register_state`] which returns self by-value.
+    pub fn with_state<T: Send + Sync + 'static>(mut self, variants: Vec<T>) -> Self {
+        self.register_state(variants);
+        self
+    }
+
+    pub(crate) fn state_variants<T: 'static>(&self) -> Option<&[T]> {
+        self.state_variants
+            .get(&TypeId::of::<T>())
+            .and_then(|variants| variants.downcast_ref::<Vec<T>>())
+            .map(Vec::as_slice)
+    }
+
+    /// Backs the resource `T` with the file at `path`, so that the [`InspectorPlugin`](crate::InspectorPlugin)
+    /// shows buttons for saving it to the file as RON and for reloading it from there.
+    /// ```rust,no_run
+    /// # use bevy::prelude::*;
+    /// # use bevy_inspector_egui::{Inspectable, InspectableRegistry, InspectorPlugin};
+    /// # use serde::{Deserialize, Serialize};
+    /// #[derive(Inspectable, Default, Serialize, Deserialize)]
+    /// struct Config {
+    ///     gravity: f32,
+    /// }
+    ///
+    /// let mut registry = InspectableRegistry::default();
+    /// registry.register_resource_file::<Config>("assets/config.ron");
+    ///
+    /// App::build()
+    ///     .insert_resource(registry)
+    ///     .add_plugin(InspectorPlugin::<Config>::new())
+    ///     .run();
+    /// ```
+    pub fn register_resource_file<T>(&mut self, path: impl Into<PathBuf>)
+    where
+        T: Serialize + DeserializeOwned + 'static,
+    {
+        let serialize = |value: &dyn std::any::Any| {
+            let value = value.downcast_ref::<T>().unwrap();
+            ron::ser::to_string_pretty(value, ron::ser::PrettyConfig::default())
+        };
+        let deserialize = |value: &mut dyn std::any::Any, text: &str| {
+            *value.downcast_mut::<T>().unwrap() = ron::from_str(text)?;
+            Ok(())
+        };
+        let resource_file = ResourceFile {
+            path: path.into(),
+            serialize: Box::new(serialize),
+            deserialize: Box::new(deserialize),
+        };
+        self.resource_files.insert(TypeId::of::<T>(), resource_file);
+    }
+
+    /// Annotates the component `T` with a label, e.g. the plugin that adds it,
+    /// which the [`WorldInspectorPlugin`](crate::WorldInspectorPlugin) shows as a badge next to its name.
+    /// ```rust,no_run
+    /// # use bevy_inspector_egui::InspectableRegistry;
+    /// struct RigidBody;
+    ///
+    /// let mut registry = InspectableRegistry::default();
+    /// registry.annotate::<RigidBody>("PhysicsPlugin");
+    /// ```
+    pub fn annotate<T: 'static>(&mut self, annotation: impl Into<String>) {
+        self.annotations
+            .insert(TypeId::of::<T>(), annotation.into());
+    }
+
+    /// Variant of [`InspectableRegistry::annotate`] which returns self by-value.
+    pub fn with_annotation<T: 'static>(mut self, annotation: impl Into<String>) -> Self {
+        self.annotate::<T>(annotation);
+        self
+    }
+
+    /// Applies all of the `registrations`, for plugins which need to register many types at once.
+    /// ```rust,no_run
+    /// # use bevy_inspector_egui::{InspectableRegistration, InspectableRegistry};
+    /// # #[derive(bevy_inspector_egui::Inspectable)] struct Health(f32);
+    /// # #[derive(bevy_inspector_egui::Inspectable)] struct Mana(f32);
+    /// struct Score(u32);
+    ///
+    /// const REGISTRATIONS: &[&dyn InspectableRegistration] = &[
+    ///     &InspectableRegistry::register::<Health>,
+    ///     &InspectableRegistry::register::<Mana>,
+    ///     &|registry: &mut InspectableRegistry| {
+    ///         registry.register_display(|score: &Score| score.0.to_string())
+    ///     },
+    /// ];
+    ///
+    /// let mut registry = InspectableRegistry::default();
+    /// registry.register_all(REGISTRATIONS);
+    /// ```
+    pub fn register_all(&mut self, registrations: &[&dyn InspectableRegistration]) {
+        for registration in registrations {
+            registration.register(self);
+        }
+    }
+
     /// Variant of [`InspectableRegistry::register`] which returns self by-value.
     /// Allows
     /// ```rust,no_run
@@ -76,6 +274,10 @@ impl Default for InspectableRegistry {
     fn default() -> Self {
         let mut this = InspectableRegistry {
             impls: HashMap::default(),
+            defaults: HashMap::default(),
+            state_variants: HashMap::default(),
+            annotations: HashMap::default(),
+            resource_files: HashMap::default(),
         };
 
         this.register::<std::ops::Range<f32>>();
@@ -87,6 +289,9 @@ impl Default for InspectableRegistry {
 
         this.register::<Color>();
         this.register::<bevy::asset::HandleId>();
+        this.register::<HandleUntyped>();
+        this.register::<Sprite>();
+        this.register::<SpriteResizeMode>();
         this.register::<TextureAtlasSprite>();
         this.register::<TextureAtlas>();
         this.register::<Light>();
@@ -100,6 +305,9 @@ impl Default for InspectableRegistry {
         this.register::<ScalingMode>();
         this.register::<DepthCalculation>();
         this.register::<VisibleEntities>();
+        this.register::<Visible>();
+        this.register::<Parent>();
+        this.register::<Children>();
 
         this.register::<Handle<Texture>>();
         this.register::<Handle<StandardMaterial>>();
@@ -109,11 +317,21 @@ impl Default for InspectableRegistry {
 
         this.register::<ClearColor>();
         this.register::<AmbientLight>();
+        this.register::<Msaa>();
+        this.register::<Time>();
+        this.register::<AssetServer>();
+        this.register::<Input<GamepadButton>>();
+        this.register::<Axis<GamepadAxis>>();
+        this.register::<Axis<GamepadButton>>();
 
-        register!(this Display, Style, Size<f32>, Size<Val>, Val, bevy::ui::FocusPolicy);
+        register!(this Display, Style, Size<f32>, Size<Val>, Rect<Val>, Val, bevy::ui::FocusPolicy);
         register!(this VerticalAlign, HorizontalAlign, TextAlignment, TextStyle, TextSection, Text);
         register!(this PositionType, Direction, FlexDirection, FlexWrap, AlignItems, AlignSelf, JustifyContent);
 
+        #[cfg(feature = "gltf")]
+        register!(this Handle<bevy::gltf::Gltf>);
+        #[cfg(feature = "audio")]
+        register!(this Handle<bevy::audio::AudioSource>);
         #[cfg(feature = "rapier")]
         register!(this bevy_rapier3d::rapier::dynamics::MassProperties, bevy_rapier3d::rapier::dynamics::RigidBody, bevy_rapier3d::physics::RigidBodyHandleComponent);
         #[cfg(feature = "rapier2d")]
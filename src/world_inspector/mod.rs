@@ -1,12 +1,24 @@
+mod component_log;
+mod export;
+mod filter;
+mod graph;
 pub(crate) mod impls;
 mod inspectable_registry;
+mod multi_edit;
 mod plugin;
+mod recorder;
+mod search;
+pub(crate) mod snapshot;
+mod views;
 
 use bevy::{render::camera::Camera, window::WindowId};
-pub use inspectable_registry::InspectableRegistry;
+pub use filter::WorldInspectorFilter;
+pub use inspectable_registry::{InspectableRegistration, InspectableRegistry};
 pub use plugin::WorldInspectorPlugin;
+pub use views::WorldInspectorViews;
 
 use bevy::{
+    diagnostic::{Diagnostics, FrameTimeDiagnosticsPlugin},
     ecs::{
         component::{ComponentId, ComponentInfo, ComponentTicks, StorageType},
         entity::EntityLocation,
@@ -16,19 +28,24 @@ use bevy::{
     prelude::*,
     reflect::{TypeRegistryArc, TypeRegistryInternal},
     render::render_graph::base::MainPass,
-    utils::HashSet,
+    utils::{HashMap, HashSet},
 };
 use bevy_egui::egui::{self, Color32};
 use egui::CollapsingHeader;
 use pretty_type_name::pretty_type_name_str;
-use std::{any::TypeId, borrow::Cow, cell::Cell};
+use std::{any::TypeId, borrow::Cow, cell::Cell, collections::BTreeMap, path::PathBuf};
 
 use crate::{
-    utils::{sort_iter_if, ui::label_button},
+    utils::{error_label, sort_iter_if, ui::label_button},
     Context,
 };
+use component_log::ComponentLog;
+use graph::RelationshipGraph;
 use impls::EntityAttributes;
 use inspectable_registry::InspectCallback;
+use recorder::EditRecorder;
+use search::DeepSearch;
+use snapshot::WorldSnapshot;
 
 /// Resource which controls the way the world inspector is shown.
 #[derive(Debug, Clone)]
@@ -39,12 +56,129 @@ pub struct WorldInspectorParams {
     pub read_only_components: HashSet<TypeId>,
     /// Whether to sort the components alphabetically
     pub sort_components: bool,
+    /// These components are shown first, in this order.
+    /// If the list isn't empty, the remaining components follow alphabetically.
+    pub component_order: Vec<TypeId>,
+    /// Whether to hide components which can't be edited, because they are read only or neither registered
+    /// in the [`InspectableRegistry`] nor with `#[reflect(Component)]`.
+    /// This can also be toggled at the top of the inspector.
+    pub editable_components_only: bool,
+    /// Whether to hide the components of the engine, so that mostly the components of your own crate are shown.
+    /// Components are detected by the prefix of their type path, see [`WorldInspectorParams::engine_prefixes`].
+    pub user_components_only: bool,
+    /// Components whose type path starts with one of these prefixes are hidden by `user_components_only`.
+    /// Defaults to `bevy_` and `bevy::`.
+    pub engine_prefixes: Vec<String>,
+    /// Components whose type path starts with one of these prefixes are shown even if they match
+    /// one of the `engine_prefixes`, e.g. for your own crates named `bevy_*`.
+    pub user_prefixes: Vec<String>,
     /// Controls whether the world inspector is shown
     pub enabled: bool,
     /// Whether entities can be despawned
     pub despawnable_entities: bool,
     /// The window the inspector should be displayed on
     pub window: WindowId,
+    /// Whether to dock the inspector to a side of the window instead of showing it as a floating window.
+    /// Docked inspectors can't be closed, use [`WorldInspectorParams::enabled`] to hide them.
+    pub panel_side: Option<PanelSide>,
+    /// Selected entities, toggled by ctrl- or shift-clicking an entity.
+    /// Their components are shown at the top of the inspector.
+    /// When more than one entity is selected, the components they have in common are shown together
+    /// and edits are applied to all of them. This requires the components to be registered
+    /// with `#[reflect(Component)]`.
+    pub selected: HashSet<Entity>,
+    /// Whether to select the first root entity if nothing is selected.
+    /// This only happens once, so deselecting it afterwards is respected.
+    /// When the world becomes empty, the first entity to show up will get selected again.
+    pub auto_select_first: bool,
+    /// Whether to only list entities without a `Parent` at the top level.
+    /// Children are then reachable by expanding their parent.
+    /// When disabled, every entity is listed at the top level, including children.
+    pub roots_only: bool,
+    /// Whether dragged numbers are only written to the component once the drag is released,
+    /// instead of changing it (and triggering change detection) every frame.
+    pub commit_on_release: bool,
+    /// Whether to only show the fields of a component which differ from its default value.
+    /// This only applies to components whose default was registered using
+    /// [`InspectableRegistry::register_default`].
+    pub diff_from_default: bool,
+    /// Whether two selected entities are shown side by side instead of being edited together.
+    /// Fields which differ between them are highlighted.
+    /// Only components registered with `#[reflect(Component)]` can be compared.
+    pub compare_selected: bool,
+    /// Whether to draw a graph of the selected entities, their parents and children,
+    /// and the entities referenced in their components. Clicking an entity selects it.
+    pub show_relationship_graph: bool,
+    /// Whether components are expanded or collapsed per component type instead of per entity,
+    /// so that collapsing a component collapses it on every entity.
+    pub expansion_by_type: bool,
+    /// Whether to show zero-sized marker components like other components.
+    /// When disabled, they are listed in a single line instead.
+    pub show_markers: bool,
+    /// Whether components stored in sparse sets instead of tables show a `sparse set` badge.
+    /// Sparse set components are faster to add and remove, but slower to iterate over.
+    pub show_storage_badges: bool,
+    /// Whether to show the size in bytes of each component, and of all components of an entity
+    pub show_sizes: bool,
+    /// Whether to show buttons for taking an in-memory snapshot of all components and restoring it later.
+    /// Only components registered with `#[reflect(Component)]` which aren't ignored are included.
+    pub show_snapshot_buttons: bool,
+    /// Whether to show buttons for exporting the selected or all listed entities as a RON scene.
+    /// Only components registered with `#[reflect(Component)]` which aren't ignored are included.
+    pub show_export_buttons: bool,
+    /// The file exported scenes are written to. If `None`, they are copied to the clipboard instead.
+    pub export_path: Option<PathBuf>,
+    /// Whether to show buttons for recording the edits made in the inspector, saving them to
+    /// [`WorldInspectorParams::edit_script_path`] and replaying them later, e.g. in a fresh world.
+    /// Only edits of components registered with `#[reflect(Component)]` on entities with a `Name` are recorded,
+    /// and entities are matched by their `Name` when replaying.
+    pub show_edit_recorder: bool,
+    /// The file recorded edits are saved to and loaded from
+    pub edit_script_path: PathBuf,
+    /// Whether to show a search field for finding entities by the values of their components.
+    /// Only components registered with `#[reflect(Component)]` which aren't ignored are searched,
+    /// and the matching entities are highlighted.
+    /// The matches are only updated when the search text changes or "Refresh" is clicked.
+    pub deep_search: bool,
+    /// Whether to show a log of the components recently added to or removed from entities.
+    /// Only components registered in the [`InspectableRegistry`] or with `#[reflect(Component)]`
+    /// which aren't ignored are logged. Clicking an entry selects its entity.
+    pub show_component_log: bool,
+    /// How many entities are listed at most, after filtering. The remaining ones are only counted.
+    /// Children shown when expanding an entity don't count towards the limit.
+    pub max_visible_entities: Option<usize>,
+    /// These components are always shown expanded, without a collapsing header
+    pub always_expanded: HashSet<TypeId>,
+    /// Whether entities with children show how many descendants they have, e.g. `Enemies (23)`.
+    /// Only descendants matching the [`WorldInspectorFilter`] are counted.
+    pub show_child_counts: bool,
+    /// Whether every entity starts collapsed whenever the inspector is opened,
+    /// instead of keeping the state egui remembers from the last time it was open.
+    pub collapse_on_open: bool,
+    /// Whether the components of an entity are listed by name, showing only the widget of the clicked one
+    /// next to the list, instead of one collapsing header per component
+    pub component_detail_view: bool,
+    /// How far children are indented relative to their parent
+    pub indent_width: f32,
+    /// Whether to draw lines connecting child entities to their parent, like a file tree
+    pub show_tree_lines: bool,
+    /// Whether entity and component names which don't fit into the window are cut off with an ellipsis,
+    /// showing the full name on hover. Otherwise the window grows to fit them.
+    pub truncate_labels: bool,
+    /// Whether dragging with two fingers scrolls the inspector and pinching changes the ui scale, while the inspector's window is focused.
+    /// Note that the ui scale is [`EguiSettings::scale_factor`](bevy_egui::EguiSettings), which affects all egui windows.
+    pub touch_gestures: bool,
+    /// Whether to show the average frames per second and frame time.
+    /// Requires the [`FrameTimeDiagnosticsPlugin`], otherwise `FPS: n/a` is displayed.
+    pub show_fps: bool,
+    /// Whether numbers, strings and bools show a button copying their value to the clipboard
+    pub show_copy_buttons: bool,
+    /// Whether to request a repaint every frame while the inspector is open, so that values which change
+    /// on their own are shown live. When disabled, repaints are left to the egui integration.
+    pub continuous_repaint: bool,
+    /// How deep nested fields and collections of a component are displayed,
+    /// deeper values show `(max depth reached)` instead
+    pub max_depth: usize,
 }
 
 impl WorldInspectorParams {
@@ -53,9 +187,46 @@ impl WorldInspectorParams {
             ignore_components: HashSet::default(),
             read_only_components: HashSet::default(),
             sort_components: false,
+            component_order: Vec::new(),
+            editable_components_only: false,
+            user_components_only: false,
+            engine_prefixes: vec!["bevy_".to_string(), "bevy::".to_string()],
+            user_prefixes: Vec::new(),
             enabled: true,
             despawnable_entities: false,
             window: WindowId::primary(),
+            panel_side: None,
+            selected: HashSet::default(),
+            auto_select_first: false,
+            roots_only: true,
+            commit_on_release: false,
+            diff_from_default: false,
+            compare_selected: false,
+            show_relationship_graph: false,
+            expansion_by_type: false,
+            show_markers: true,
+            show_storage_badges: false,
+            show_sizes: false,
+            show_snapshot_buttons: false,
+            show_export_buttons: false,
+            export_path: None,
+            show_edit_recorder: false,
+            edit_script_path: PathBuf::from("inspector_edits.ron"),
+            deep_search: false,
+            show_component_log: false,
+            max_visible_entities: None,
+            always_expanded: HashSet::default(),
+            show_child_counts: false,
+            collapse_on_open: false,
+            component_detail_view: false,
+            indent_width: 25.0,
+            show_tree_lines: false,
+            truncate_labels: false,
+            touch_gestures: false,
+            show_fps: false,
+            show_copy_buttons: false,
+            continuous_repaint: false,
+            max_depth: 32,
         }
     }
 
@@ -64,6 +235,35 @@ impl WorldInspectorParams {
         self.ignore_components.insert(TypeId::of::<T>());
     }
 
+    /// Show `T` before all other components, after the ones already in the component order
+    pub fn pin_component<T: 'static>(&mut self) {
+        self.component_order.push(TypeId::of::<T>());
+    }
+
+    /// Always show `T` expanded, without a collapsing header
+    pub fn expand_component<T: 'static>(&mut self) {
+        self.always_expanded.insert(TypeId::of::<T>());
+    }
+
+    /// Position of the component in the component order, unordered components come last
+    fn component_priority(&self, type_id: Option<TypeId>) -> usize {
+        type_id
+            .and_then(|type_id| self.component_order.iter().position(|&t| t == type_id))
+            .unwrap_or(usize::MAX)
+    }
+
+    /// Whether the component with the type path `name` is hidden by `user_components_only`
+    fn is_hidden_engine_component(&self, name: &str) -> bool {
+        let starts_with_any = |prefixes: &[String]| {
+            prefixes
+                .iter()
+                .any(|prefix| name.starts_with(prefix.as_str()))
+        };
+        self.user_components_only
+            && starts_with_any(&self.engine_prefixes)
+            && !starts_with_any(&self.user_prefixes)
+    }
+
     fn should_ignore_component(&self, type_id: TypeId) -> bool {
         self.ignore_components.contains(&type_id)
     }
@@ -72,101 +272,1347 @@ impl WorldInspectorParams {
         self.read_only_components.contains(&type_id)
     }
 
-    fn entity_options(&self) -> EntityAttributes {
-        EntityAttributes {
-            despawnable: self.despawnable_entities,
+    fn is_always_expanded(&self, type_id: TypeId) -> bool {
+        self.always_expanded.contains(&type_id)
+    }
+
+    fn entity_options(&self) -> EntityAttributes {
+        EntityAttributes {
+            despawnable: self.despawnable_entities,
+        }
+    }
+}
+
+impl Default for WorldInspectorParams {
+    fn default() -> Self {
+        let mut params = WorldInspectorParams::empty();
+
+        params.ignore_components = [
+            TypeId::of::<Name>(),
+            TypeId::of::<PreviousParent>(),
+            TypeId::of::<MainPass>(),
+            TypeId::of::<Draw>(),
+            TypeId::of::<RenderPipelines>(),
+            TypeId::of::<InspectorHovered>(),
+        ]
+        .iter()
+        .copied()
+        .collect();
+        params.read_only_components = [TypeId::of::<GlobalTransform>()].iter().copied().collect();
+
+        params
+    }
+}
+
+/// The side of the window a docked world inspector is shown at, see [`WorldInspectorParams::panel_side`].
+///
+/// egui currently only has panels for the left and the top side.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PanelSide {
+    /// A panel covering the left side of the window
+    Left,
+    /// A panel covering the top of the window
+    Top,
+}
+impl PanelSide {
+    const WIDTH: f32 = 350.0;
+    const HEIGHT: f32 = 300.0;
+}
+
+/// Whether `WorldInspectorParams::auto_select_first` already selected an entity.
+#[derive(Default)]
+struct AutoSelectState {
+    selected_once: bool,
+}
+
+/// The entity which was last jumped to by selecting it through an `Entity` field.
+/// Its row in the world inspector gets highlighted for a short time.
+struct EntityPing {
+    entity: Entity,
+    time: f64,
+}
+impl EntityPing {
+    const DURATION: f64 = 0.5;
+}
+
+/// Resource for drawing the world inspector into an egui context other than the one of
+/// [`WorldInspectorParams::window`], for example one which is rendered onto a texture in the world.
+///
+/// The world inspector is drawn during [`CoreStage::Update`],
+/// so the context has to be between `begin_frame` and `end_frame` at that point.
+pub struct WorldInspectorContext(pub egui::CtxRef);
+
+/// Marker component which the world inspector inserts on the entity whose row is hovered,
+/// and removes once the row is no longer hovered.
+/// It can be used to highlight the hovered entity in the game.
+/// ```rust
+/// use bevy::prelude::*;
+/// use bevy_inspector_egui::InspectorHovered;
+///
+/// fn highlight(hovered: Query<&Transform, With<InspectorHovered>>) {
+///     for transform in hovered.iter() {
+///         // draw an outline
+///     }
+/// }
+/// ```
+#[derive(Debug, Default, Clone, Copy)]
+pub struct InspectorHovered;
+
+/// An entity to select and jump to, requested by a widget which can't change
+/// the [`WorldInspectorParams`] while the world inspector is drawn.
+struct PendingJump(Entity);
+
+/// How often the ancestors of an entity were opened by "Reveal in hierarchy".
+/// Like in [`ComponentExpansion`], the counter is part of the header ids,
+/// so that the headers of the ancestors are recreated in the open state.
+#[derive(Default)]
+struct RevealedAncestors(HashMap<Entity, u32>);
+
+/// How often the inspector was opened with `WorldInspectorParams::collapse_on_open`.
+/// The counter is part of the entity header ids, so that the hierarchy is recreated collapsed.
+#[derive(Default)]
+struct HierarchyCollapse {
+    was_enabled: bool,
+    counter: u32,
+}
+
+/// The component shown next to the component list of each entity, for `WorldInspectorParams::component_detail_view`.
+#[derive(Default)]
+struct DetailSelection(HashMap<Entity, ComponentId>);
+
+/// The entity whose row is scrolled into view once it is drawn.
+struct RevealTarget(Entity);
+
+/// Whether components are expanded, per type, for `WorldInspectorParams::expansion_by_type`.
+/// The counter is part of the header ids, so that toggling a component on one entity
+/// replaces the state egui remembers for the headers of every other entity.
+#[derive(Default)]
+struct ComponentExpansion(HashMap<TypeId, (bool, u32)>);
+
+/// Started by the "Watch changes" button, highlights every entity other than the watched ones
+/// which had a component changed since the change tick `since`.
+struct ChangeWatch {
+    watched: HashSet<Entity>,
+    since: u32,
+}
+
+/// Requested by the "Apply to all" action of a component header, waiting for confirmation:
+/// the value of `component` on `entity` is copied to every other entity with that component.
+struct ApplyToAll {
+    entity: Entity,
+    component: ComponentId,
+}
+
+/// Requested by the "Rename" action of an entity's context menu, editing the new `name` until it is confirmed.
+struct RenameEntity {
+    entity: Entity,
+    name: String,
+}
+
+/// The component which was last edited in the world inspector.
+struct LastEdit {
+    entity: Entity,
+    component: ComponentId,
+    name: String,
+}
+
+struct WorldUIContext<'a> {
+    world: &'a mut World,
+    ui_ctx: Option<&'a egui::CtxRef>,
+    delete_entity: Cell<Option<Entity>>,
+    duplicate_entity: Cell<Option<Entity>>,
+    rename_entity: Cell<Option<Entity>>,
+    toggle_selection: Cell<Option<Entity>>,
+    jump_to: Cell<Option<Entity>>,
+    hovered: Cell<Option<Entity>>,
+    toggle_expansion: Cell<Option<(TypeId, (bool, u32))>>,
+    select_detail: Cell<Option<(Entity, ComponentId)>>,
+    set_editable_only: Cell<Option<bool>>,
+    apply_to_all: Cell<Option<(Entity, ComponentId)>>,
+    last_edit: Cell<Option<(Entity, ComponentId)>>,
+    revealed: Cell<bool>,
+}
+impl<'a> WorldUIContext<'a> {
+    fn new(ui_ctx: Option<&'a egui::CtxRef>, world: &'a mut World) -> WorldUIContext<'a> {
+        WorldUIContext {
+            world,
+            ui_ctx,
+            delete_entity: Cell::new(None),
+            duplicate_entity: Cell::new(None),
+            rename_entity: Cell::new(None),
+            toggle_selection: Cell::new(None),
+            jump_to: Cell::new(None),
+            hovered: Cell::new(None),
+            toggle_expansion: Cell::new(None),
+            select_detail: Cell::new(None),
+            set_editable_only: Cell::new(None),
+            apply_to_all: Cell::new(None),
+            last_edit: Cell::new(None),
+            revealed: Cell::new(false),
+        }
+    }
+}
+
+impl Drop for WorldUIContext<'_> {
+    fn drop(&mut self) {
+        if let Some((entity, component)) = self.last_edit.get() {
+            let name = self.world.components().get_info(component).unwrap().name();
+            let name = format!(
+                "{} of {}",
+                pretty_type_name_str(name),
+                self.entity_name(entity)
+            );
+            self.world.insert_resource(LastEdit {
+                entity,
+                component,
+                name,
+            });
+
+            let recording = matches!(self.world.get_resource::<EditRecorder>(), Some(recorder) if recorder.recording);
+            let type_id = self
+                .world
+                .components()
+                .get_info(component)
+                .unwrap()
+                .type_id();
+            if let (true, Some(type_id)) = (recording, type_id) {
+                self.world
+                    .resource_scope(|world, mut recorder: Mut<EditRecorder>| {
+                        recorder.error = recorder.record(world, entity, type_id).err();
+                    });
+            }
+        }
+        if let Some((type_id, expansion)) = self.toggle_expansion.get() {
+            let mut expansions = self
+                .world
+                .get_resource_or_insert_with(ComponentExpansion::default);
+            expansions.0.insert(type_id, expansion);
+        }
+        if let Some((entity, component)) = self.select_detail.get() {
+            let mut selection = self
+                .world
+                .get_resource_or_insert_with(DetailSelection::default);
+            selection.0.insert(entity, component);
+        }
+        if let Some((entity, component)) = self.apply_to_all.get() {
+            self.world.insert_resource(ApplyToAll { entity, component });
+        }
+        if self.revealed.get() {
+            self.world.remove_resource::<RevealTarget>();
+        }
+        if let Some(entity) = self.rename_entity.get() {
+            let name = self
+                .world
+                .get::<Name>(entity)
+                .map(|name| name.as_str().to_string())
+                .unwrap_or_default();
+            self.world.insert_resource(RenameEntity { entity, name });
+        }
+        if let Some(entity) = self.duplicate_entity.get() {
+            let copy = snapshot::duplicate(self.world, entity);
+            self.world.insert_resource(PendingJump(copy));
+        }
+        if let Some(entity) = self.delete_entity.get() {
+            despawn_with_children_recursive(self.world, entity);
+        }
+    }
+}
+
+impl<'a> WorldUIContext<'a> {
+    fn entity_name(&self, entity: Entity) -> Cow<'_, str> {
+        match self.world.get_entity(entity) {
+            Some(entity) => guess_entity_name(entity),
+            None => format!("Entity {} (inexistent)", entity.id()).into(),
+        }
+    }
+
+    fn fps_ui(&self, ui: &mut egui::Ui) {
+        let diagnostics = self.world.get_resource::<Diagnostics>();
+        let average = |id| {
+            diagnostics
+                .and_then(|diagnostics| diagnostics.get(id))
+                .and_then(|diagnostic| diagnostic.average())
+        };
+
+        match (
+            average(FrameTimeDiagnosticsPlugin::FPS),
+            average(FrameTimeDiagnosticsPlugin::FRAME_TIME),
+        ) {
+            (Some(fps), Some(frame_time)) => {
+                ui.label(format!("FPS: {:.1} ({:.2} ms)", fps, frame_time * 1000.0));
+            }
+            (Some(fps), None) => {
+                ui.label(format!("FPS: {:.1}", fps));
+            }
+            _ => {
+                ui.label("FPS: n/a");
+            }
+        }
+    }
+
+    fn world_ui<F>(&mut self, ui: &mut egui::Ui, params: &WorldInspectorParams) -> bool
+    where
+        F: WorldQuery,
+        F::Fetch: FilterFetch,
+    {
+        // the entities are unique themselves, because only one WorldInspector can exist
+        let dummy_id = egui::Id::new(42);
+        let entity_options = params.entity_options();
+
+        let mut changed = false;
+
+        ui.spacing_mut().indent = params.indent_width;
+
+        if params.show_fps {
+            self.fps_ui(ui);
+        }
+
+        let mut editable_only = params.editable_components_only;
+        if ui
+            .checkbox(&mut editable_only, "Editable components only")
+            .changed()
+        {
+            self.set_editable_only.set(Some(editable_only));
+        }
+
+        if let Some(last_edit) = self.world.get_resource::<LastEdit>() {
+            ui.horizontal(|ui| {
+                ui.label(format!("Last edit: {}", last_edit.name));
+                if label_button(ui, "➡ Jump", Color32::LIGHT_BLUE) {
+                    self.jump_to.set(Some(last_edit.entity));
+                }
+            });
+        }
+
+        if params.show_snapshot_buttons {
+            changed |= self.snapshot_ui(ui, params);
+            ui.separator();
+        }
+
+        if params.show_edit_recorder {
+            changed |= self.edit_recorder_ui(ui, params);
+            ui.separator();
+        }
+
+        if params.show_export_buttons {
+            self.export_ui::<F>(ui, params);
+            ui.separator();
+        }
+
+        if params.deep_search {
+            self.deep_search_ui(ui, params);
+            ui.separator();
+        }
+
+        if params.show_component_log {
+            self.component_log_ui(ui);
+        }
+
+        if self.world.contains_resource::<ApplyToAll>() {
+            changed |= self.apply_to_all_ui(ui);
+        }
+
+        if self.world.contains_resource::<RenameEntity>() {
+            changed |= self.rename_ui(ui);
+        }
+
+        if !params.selected.is_empty() || self.world.contains_resource::<ChangeWatch>() {
+            self.change_watch_ui(ui, params);
+        }
+
+        if !params.selected.is_empty() {
+            changed |= self.selection_ui(ui, params, dummy_id);
+            if params.show_relationship_graph {
+                self.relationship_graph_ui(ui, params, dummy_id);
+            }
+            ui.separator();
+        }
+
+        if self.world.contains_resource::<WorldInspectorViews>() {
+            changed |= self.views_ui(ui, params, dummy_id, &entity_options);
+        }
+
+        let entities: Vec<Entity> = if params.roots_only {
+            let mut root_entities = self.world.query_filtered::<Entity, (Without<Parent>, F)>();
+            root_entities.iter(self.world).collect()
+        } else {
+            let mut entities = self.world.query_filtered::<Entity, F>();
+            entities.iter(self.world).collect()
+        };
+
+        let mut entities: Vec<Entity> = match self.world.get_resource::<WorldInspectorFilter>() {
+            Some(filter) => entities
+                .into_iter()
+                .filter(|&entity| filter.matches(&self.world.entity(entity)))
+                .collect(),
+            None => entities,
+        };
+
+        let hidden = match params.max_visible_entities {
+            Some(max) if entities.len() > max => {
+                let hidden = entities.len() - max;
+                entities.truncate(max);
+                hidden
+            }
+            _ => 0,
+        };
+
+        match self.grouping_ui(ui) {
+            Some(grouping) => {
+                changed |=
+                    self.grouped_ui(ui, grouping, &entities, params, dummy_id, &entity_options);
+            }
+            None => {
+                for entity in entities {
+                    changed |= self.entity_ui(ui, entity, params, dummy_id, &entity_options);
+                }
+            }
+        }
+
+        if hidden > 0 {
+            ui.label(format!("… and {} more", hidden));
+        }
+
+        changed
+    }
+
+    /// Shows a dropdown for picking the active grouping, if there are any, and returns it.
+    fn grouping_ui(&mut self, ui: &mut egui::Ui) -> Option<usize> {
+        let mut views = self.world.get_resource_mut::<WorldInspectorViews>()?;
+        if views.groupings.is_empty() {
+            return None;
+        }
+
+        let mut active_grouping = views.active_grouping;
+        let selected_text = match active_grouping {
+            Some(i) => views.groupings[i].0.as_str(),
+            None => "None",
+        };
+        ui.horizontal(|ui| {
+            ui.label("Group by");
+            egui::ComboBox::from_id_source("world inspector grouping")
+                .selected_text(selected_text)
+                .show_ui(ui, |ui| {
+                    ui.selectable_value(&mut active_grouping, None, "None");
+                    for (i, (name, _)) in views.groupings.iter().enumerate() {
+                        ui.selectable_value(&mut active_grouping, Some(i), name);
+                    }
+                });
+        });
+
+        if views.active_grouping != active_grouping {
+            views.active_grouping = active_grouping;
+        }
+        active_grouping
+    }
+
+    fn grouped_ui(
+        &self,
+        ui: &mut egui::Ui,
+        grouping: usize,
+        entities: &[Entity],
+        params: &WorldInspectorParams,
+        id: egui::Id,
+        entity_options: &EntityAttributes,
+    ) -> bool {
+        let views = self.world.get_resource::<WorldInspectorViews>().unwrap();
+        let (_, key) = &views.groupings[grouping];
+
+        let mut groups: BTreeMap<String, Vec<Entity>> = BTreeMap::new();
+        let mut ungrouped = Vec::new();
+        for &entity in entities {
+            match key(&self.world.entity(entity)) {
+                Some(key) => groups.entry(key).or_default().push(entity),
+                None => ungrouped.push(entity),
+            }
+        }
+        let mut groups: Vec<(String, Vec<Entity>)> = groups.into_iter().collect();
+        if !ungrouped.is_empty() {
+            groups.push(("(ungrouped)".to_string(), ungrouped));
+        }
+
+        let mut changed = false;
+        for (key, entities) in &groups {
+            let group_id = id.with("group").with(key);
+            CollapsingHeader::new(format!("{} ({})", key, entities.len()))
+                .id_source(group_id)
+                .show(ui, |ui| {
+                    for &entity in entities {
+                        changed |= self.entity_ui(ui, entity, params, group_id, entity_options);
+                    }
+                });
+        }
+        changed
+    }
+
+    fn snapshot_ui(&mut self, ui: &mut egui::Ui, params: &WorldInspectorParams) -> bool {
+        let mut take = false;
+        let mut restore = false;
+        let snapshot_len = self
+            .world
+            .get_resource::<WorldSnapshot>()
+            .map(WorldSnapshot::len);
+
+        ui.horizontal(|ui| {
+            take = ui.button("Snapshot").clicked();
+            restore = ui
+                .add(egui::Button::new("Restore").enabled(snapshot_len.is_some()))
+                .clicked();
+            if let Some(len) = snapshot_len {
+                ui.label(format!("{} components", len));
+            }
+        });
+
+        if take {
+            let snapshot = WorldSnapshot::take(self.world, params);
+            self.world.insert_resource(snapshot);
+        }
+        if restore {
+            self.world
+                .resource_scope(|world, snapshot: Mut<WorldSnapshot>| snapshot.restore(world));
+        }
+
+        restore
+    }
+
+    fn edit_recorder_ui(&mut self, ui: &mut egui::Ui, params: &WorldInspectorParams) -> bool {
+        let recorder = self
+            .world
+            .get_resource_or_insert_with(EditRecorder::default);
+        let recording = recorder.recording;
+        let len = recorder.edits.len();
+        if let Some(error) = &recorder.error {
+            error_label(ui, format!("Last edit was not recorded: {}", error));
+        }
+
+        let mut toggle_recording = false;
+        let mut save = false;
+        let mut load = false;
+        let mut replay = false;
+        ui.horizontal(|ui| {
+            let record = match recording {
+                true => "⏹ Stop",
+                false => "⏺ Record",
+            };
+            toggle_recording = ui.button(record).clicked();
+            save = ui.add(egui::Button::new("Save").enabled(len > 0)).clicked();
+            load = ui.button("Load").clicked();
+            replay = ui
+                .add(egui::Button::new("Replay").enabled(len > 0 && !recording))
+                .clicked();
+            ui.label(format!("{} edits", len));
+        });
+
+        let path = &params.edit_script_path;
+        let mut recorder = self.world.get_resource_mut::<EditRecorder>().unwrap();
+        if toggle_recording {
+            recorder.recording = !recording;
+            recorder.error = None;
+            if !recording {
+                recorder.edits.clear();
+            }
+        }
+        if save {
+            match recorder.save(path) {
+                Ok(()) => info!("saved {} edits to {}", len, path.display()),
+                Err(error) => warn!("failed to save edits to {}: {}", path.display(), error),
+            }
+        }
+        if load {
+            match recorder.load(path) {
+                Ok(()) => recorder.recording = false,
+                Err(error) => warn!("failed to load edits from {}: {}", path.display(), error),
+            }
+        }
+        if replay {
+            let applied = self
+                .world
+                .resource_scope(|world, recorder: Mut<EditRecorder>| recorder.replay(world));
+            info!("replayed {} of {} edits", applied, len);
+        }
+
+        replay
+    }
+
+    /// Window asking for confirmation of a pending [`ApplyToAll`], which applies it once confirmed.
+    fn apply_to_all_ui(&mut self, ui: &mut egui::Ui) -> bool {
+        let request = self.world.get_resource::<ApplyToAll>().unwrap();
+        let (entity, component_id) = (request.entity, request.component);
+
+        let type_registry = self
+            .world
+            .get_resource::<TypeRegistryArc>()
+            .unwrap()
+            .clone();
+        let type_registry = type_registry.internal.read();
+        let component_info = self.world.components().get_info(component_id).unwrap();
+        let reflect_component = component_info
+            .type_id()
+            .and_then(|type_id| snapshot::reflect_component(&type_registry, type_id));
+        let reflect_component = match (reflect_component, self.world.get_entity(entity)) {
+            (Some(reflect_component), Some(_)) => reflect_component,
+            _ => {
+                self.world.remove_resource::<ApplyToAll>();
+                return false;
+            }
+        };
+
+        let others: Vec<Entity> = self
+            .world
+            .archetypes()
+            .iter()
+            .filter(|archetype| archetype.contains(component_id))
+            .flat_map(|archetype| archetype.entities().iter().copied())
+            .filter(|&other| other != entity)
+            .collect();
+
+        let name = format!(
+            "{} of {}",
+            pretty_type_name_str(component_info.name()),
+            self.entity_name(entity)
+        );
+        let mut apply = false;
+        let mut cancel = false;
+        egui::Window::new("Apply to all")
+            .collapsible(false)
+            .resizable(false)
+            .show(ui.ctx(), |ui| {
+                ui.label(format!(
+                    "Copy {} to {} other entities? This overwrites their values.",
+                    name,
+                    others.len()
+                ));
+                ui.horizontal(|ui| {
+                    apply = ui
+                        .add(egui::Button::new("Apply").enabled(!others.is_empty()))
+                        .clicked();
+                    cancel = ui.button("Cancel").clicked();
+                });
+            });
+
+        if apply {
+            apply_to_entities(self.world, reflect_component, entity, &others);
+        }
+        if apply || cancel {
+            self.world.remove_resource::<ApplyToAll>();
+        }
+        apply
+    }
+
+    /// Window editing the name of the entity of a pending [`RenameEntity`], which inserts a `Name` if it has none.
+    fn rename_ui(&mut self, ui: &mut egui::Ui) -> bool {
+        let mut request = self.world.remove_resource::<RenameEntity>().unwrap();
+        if self.world.get_entity(request.entity).is_none() {
+            return false;
+        }
+
+        let mut rename = false;
+        let mut cancel = false;
+        egui::Window::new("Rename")
+            .collapsible(false)
+            .resizable(false)
+            .show(ui.ctx(), |ui| {
+                ui.label(format!("New name of {}:", self.entity_name(request.entity)));
+                let response = ui.text_edit_singleline(&mut request.name);
+                rename = response.lost_focus() && ui.input().key_pressed(egui::Key::Enter);
+                ui.horizontal(|ui| {
+                    rename |= ui.button("Rename").clicked();
+                    cancel = ui.button("Cancel").clicked();
+                });
+            });
+
+        if rename {
+            match self.world.get_mut::<Name>(request.entity) {
+                Some(mut name) => name.set(request.name),
+                None => {
+                    self.world
+                        .entity_mut(request.entity)
+                        .insert(Name::new(request.name));
+                }
+            }
+        } else if !cancel {
+            self.world.insert_resource(request);
+        }
+        rename
+    }
+
+    fn export_ui<F>(&mut self, ui: &mut egui::Ui, params: &WorldInspectorParams)
+    where
+        F: WorldQuery,
+        F::Fetch: FilterFetch,
+    {
+        let mut export_selected = false;
+        let mut export_all = false;
+        ui.horizontal(|ui| {
+            export_selected = ui
+                .add(egui::Button::new("Export selected").enabled(!params.selected.is_empty()))
+                .clicked();
+            export_all = ui.button("Export all").clicked();
+        });
+
+        let entities: Vec<Entity> = if export_selected {
+            let mut selected: Vec<Entity> = params.selected.iter().copied().collect();
+            selected.sort();
+            selected
+        } else if export_all {
+            let mut entities = self.world.query_filtered::<Entity, F>();
+            let filter = self.world.get_resource::<WorldInspectorFilter>();
+            entities
+                .iter(self.world)
+                .filter(|&entity| match filter {
+                    Some(filter) => filter.matches(&self.world.entity(entity)),
+                    None => true,
+                })
+                .collect()
+        } else {
+            return;
+        };
+
+        match export::export_scene(self.world, &entities, params) {
+            Ok(scene) => export::save_scene(ui, scene, params),
+            Err(error) => bevy::log::warn!("failed to serialize scene: {}", error),
+        }
+    }
+
+    /// Button for starting or stopping to watch for changes on entities other than the selected ones.
+    fn change_watch_ui(&mut self, ui: &mut egui::Ui, params: &WorldInspectorParams) {
+        let watching = self.world.contains_resource::<ChangeWatch>();
+        let mut toggle = false;
+        ui.horizontal(|ui| {
+            let text = match watching {
+                true => "Stop watching",
+                false => "Watch changes",
+            };
+            toggle = ui.button(text).clicked();
+            if watching {
+                ui.label("Entities changed since are highlighted");
+            }
+        });
+
+        if toggle && watching {
+            self.world.remove_resource::<ChangeWatch>();
+        } else if toggle {
+            let since = self.world.read_change_tick();
+            self.world.insert_resource(ChangeWatch {
+                watched: params.selected.clone(),
+                since,
+            });
+        }
+    }
+
+    /// Whether a component of `entity` which isn't ignored changed since the change tick `since`.
+    fn changed_since(&self, entity: Entity, since: u32, params: &WorldInspectorParams) -> bool {
+        let entity_ref = match self.world.get_entity(entity) {
+            Some(entity_ref) => entity_ref,
+            None => return false,
+        };
+        let location = entity_ref.location();
+        let change_tick = self.world.read_change_tick();
+
+        let mut components = entity_ref.archetype().components();
+        components.any(|component_id| {
+            let component_info = self.world.components().get_info(component_id).unwrap();
+            if matches!(component_info.type_id(), Some(type_id) if params.should_ignore_component(type_id))
+            {
+                return false;
+            }
+            // SAFE: the ticks are only read while no component is borrowed mutably
+            match unsafe { get_component_and_ticks(self.world, component_id, entity, location) } {
+                Some((_, ticks)) => unsafe { &*ticks }.is_changed(since, change_tick),
+                None => false,
+            }
+        })
+    }
+
+    /// Collapsible list of the newest [`ComponentLog`] entries first.
+    fn component_log_ui(&mut self, ui: &mut egui::Ui) {
+        let log = match self.world.get_resource::<ComponentLog>() {
+            Some(log) => log,
+            None => return,
+        };
+
+        CollapsingHeader::new("Component log")
+            .id_source("component log")
+            .show(ui, |ui| {
+                if log.entries.is_empty() {
+                    ui.label("No components were added or removed yet");
+                    return;
+                }
+                egui::ScrollArea::from_max_height(200.0).show(ui, |ui| {
+                    egui::Grid::new("component log grid").show(ui, |ui| {
+                        for entry in log.entries.iter().rev() {
+                            ui.label(format!("#{}", entry.frame));
+                            match entry.added {
+                                true => ui.colored_label(Color32::GREEN, "+"),
+                                false => ui.colored_label(Color32::RED, "-"),
+                            };
+                            ui.label(&entry.component);
+                            let name = self.entity_name(entry.entity);
+                            match self.world.get_entity(entry.entity) {
+                                Some(_) => {
+                                    if label_button(ui, &name, Color32::LIGHT_BLUE) {
+                                        self.jump_to.set(Some(entry.entity));
+                                    }
+                                }
+                                None => {
+                                    ui.label(name.as_ref());
+                                }
+                            }
+                            ui.end_row();
+                        }
+                    });
+                });
+            });
+        ui.separator();
+    }
+
+    fn deep_search_ui(&mut self, ui: &mut egui::Ui, params: &WorldInspectorParams) {
+        let mut search = self
+            .world
+            .remove_resource::<DeepSearch>()
+            .unwrap_or_default();
+
+        ui.horizontal(|ui| {
+            ui.label("Deep search");
+            ui.text_edit_singleline(&mut search.query);
+            if ui.button("Refresh").clicked() {
+                search.refresh();
+            }
+        });
+        search.update(self.world, params);
+        if !search.query.is_empty() {
+            ui.label(match search.len() {
+                1 => "1 matching entity".to_string(),
+                len => format!("{} matching entities", len),
+            });
+        }
+
+        self.world.insert_resource(search);
+    }
+
+    fn views_ui(
+        &mut self,
+        ui: &mut egui::Ui,
+        params: &WorldInspectorParams,
+        id: egui::Id,
+        entity_options: &EntityAttributes,
+    ) -> bool {
+        let views: Vec<(String, Vec<Entity>)> =
+            self.world
+                .resource_scope(|world, views: Mut<WorldInspectorViews>| {
+                    views
+                        .views
+                        .iter()
+                        .map(|(name, view)| (name.clone(), view(world)))
+                        .collect()
+                });
+        if views.is_empty() {
+            return false;
+        }
+
+        let mut changed = false;
+        for (name, entities) in &views {
+            let view_id = id.with("views").with(name);
+            CollapsingHeader::new(format!("{} ({})", name, entities.len()))
+                .id_source(view_id)
+                .show(ui, |ui| {
+                    if entities.is_empty() {
+                        ui.label("No entities");
+                    }
+                    for &entity in entities {
+                        changed |= self.entity_ui(ui, entity, params, view_id, entity_options);
+                    }
+                });
+        }
+        ui.separator();
+
+        changed
+    }
+
+    fn selection_ui(
+        &mut self,
+        ui: &mut egui::Ui,
+        params: &WorldInspectorParams,
+        id: egui::Id,
+    ) -> bool {
+        let mut selected: Vec<Entity> = params
+            .selected
+            .iter()
+            .copied()
+            .filter(|&entity| self.world.get_entity(entity).is_some())
+            .collect();
+        selected.sort();
+        if let (true, &[a, b]) = (params.compare_selected, selected.as_slice()) {
+            return self.compare_ui(ui, params, [a, b], id);
+        }
+        let (&primary, others) = match selected.split_first() {
+            Some(split) => split,
+            None => return false,
+        };
+
+        let type_registry = self
+            .world
+            .get_resource::<TypeRegistryArc>()
+            .unwrap()
+            .clone();
+        let type_registry = type_registry.internal.read();
+
+        let entity_ref = self.world.get_entity(primary).unwrap();
+        let entity_location = entity_ref.location();
+        let archetype = entity_ref.archetype();
+        let common_components: Vec<(ComponentId, TypeId)> = archetype
+            .table_components()
+            .iter()
+            .chain(archetype.sparse_set_components())
+            .filter_map(|&component_id| {
+                let type_id = self.world.components().get_info(component_id)?.type_id()?;
+                Some((component_id, type_id))
+            })
+            .filter(|&(_, type_id)| !params.should_ignore_component(type_id))
+            .filter(|&(component_id, _)| {
+                let component_info = self.world.components().get_info(component_id).unwrap();
+                !params.is_hidden_engine_component(component_info.name())
+            })
+            .filter(|&(component_id, _)| {
+                !params.editable_components_only || self.is_editable(component_id, params)
+            })
+            .filter(|&(_, type_id)| {
+                // a single entity doesn't need to copy its components anywhere
+                others.is_empty()
+                    || type_registry
+                    .get(type_id)
+                    .and_then(|registration| registration.data::<ReflectComponent>())
+                    .is_some()
+            })
+            .filter(|&(component_id, _)| {
+                others.iter().all(|&entity| {
+                    matches!(self.world.get_entity(entity), Some(entity) if entity.contains_id(component_id))
+                })
+            })
+            .collect();
+
+        let mut changed = false;
+        let mut to_apply = Vec::new();
+        let mut reveal = false;
+
+        CollapsingHeader::new(format!("Selected ({})", selected.len()))
+            .id_source(id.with("selected entities"))
+            .default_open(true)
+            .show(ui, |ui| {
+                reveal = label_button(ui, "🔍 Reveal in hierarchy", Color32::LIGHT_BLUE);
+
+                if common_components.is_empty() {
+                    ui.label("No common components");
+                }
+
+                for &(component_id, type_id) in &common_components {
+                    let component_info = self.world.components().get_info(component_id).unwrap();
+                    let reflect_component = type_registry
+                        .get(type_id)
+                        .and_then(|registration| registration.data::<ReflectComponent>());
+
+                    let mut name = pretty_type_name_str(component_info.name());
+                    let context = Context::new_shared(None).with_id(component_id.index() as u64);
+                    let mixed = match (reflect_component, others.is_empty()) {
+                        (Some(reflect_component), false) => multi_edit::mixed_fields(
+                            self.world,
+                            &type_registry,
+                            reflect_component,
+                            primary,
+                            others,
+                            &context,
+                        ),
+                        _ => HashSet::default(),
+                    };
+                    if matches!(context.id, Some(id) if mixed.contains(&id)) {
+                        name.push_str(" (mixed)");
+                    }
+                    let before = match others.is_empty() {
+                        true => None,
+                        false => reflect_component
+                            .and_then(|reflect_component| {
+                                reflect_component.reflect_component(self.world, primary)
+                            })
+                            .map(|value| value.clone_value()),
+                    };
+
+                    let component_changed = self.component_ui(
+                        ui,
+                        name,
+                        primary,
+                        entity_location,
+                        component_info,
+                        params,
+                        id.with("selected entities"),
+                        false,
+                        Some(&mixed),
+                    );
+                    if let (true, Some(before)) = (component_changed, before) {
+                        to_apply.push((type_id, before));
+                    }
+                    changed |= component_changed;
+                }
+            });
+
+        for (type_id, before) in &to_apply {
+            let reflect_component = type_registry
+                .get(*type_id)
+                .and_then(|registration| registration.data::<ReflectComponent>())
+                .unwrap();
+            multi_edit::apply_changed_fields(
+                self.world,
+                &type_registry,
+                reflect_component,
+                primary,
+                &**before,
+                others,
+            );
+        }
+
+        if reveal {
+            self.reveal(primary, ui.input().time);
+        }
+
+        changed
+    }
+
+    fn reveal(&mut self, entity: Entity, time: f64) {
+        reveal(self.world, entity, time);
+    }
+
+    fn relationship_graph_ui(
+        &self,
+        ui: &mut egui::Ui,
+        params: &WorldInspectorParams,
+        id: egui::Id,
+    ) {
+        let mut selected: Vec<Entity> = params.selected.iter().copied().collect();
+        selected.sort();
+        let graph = RelationshipGraph::new(self.world, &selected, params);
+
+        CollapsingHeader::new("Relationships")
+            .id_source(id.with("relationship graph"))
+            .default_open(true)
+            .show(ui, |ui| {
+                let clicked = graph.ui(
+                    ui,
+                    |entity| self.entity_name(entity).into_owned(),
+                    |entity| params.selected.contains(&entity),
+                );
+                if let Some(entity) = clicked {
+                    self.jump_to.set(Some(entity));
+                }
+            });
+    }
+
+    /// Shows the components of two entities side by side, highlighting the fields which differ.
+    fn compare_ui(
+        &self,
+        ui: &mut egui::Ui,
+        params: &WorldInspectorParams,
+        entities: [Entity; 2],
+        id: egui::Id,
+    ) -> bool {
+        let type_registry = self
+            .world
+            .get_resource::<TypeRegistryArc>()
+            .unwrap()
+            .clone();
+        let type_registry = type_registry.internal.read();
+
+        let mut components: Vec<(String, ComponentId, TypeId)> = Vec::new();
+        for &entity in &entities {
+            let entity_ref = self.world.entity(entity);
+            let archetype = entity_ref.archetype();
+            for &component_id in archetype
+                .table_components()
+                .iter()
+                .chain(archetype.sparse_set_components())
+            {
+                let component_info = self.world.components().get_info(component_id).unwrap();
+                let type_id = match component_info.type_id() {
+                    Some(type_id) if !params.should_ignore_component(type_id) => type_id,
+                    _ => continue,
+                };
+                if components.iter().all(|&(_, id, _)| id != component_id) {
+                    let name = pretty_type_name_str(component_info.name());
+                    components.push((name, component_id, type_id));
+                }
+            }
+        }
+        components.sort_by(|a, b| a.0.cmp(&b.0));
+
+        let mut changed = false;
+        CollapsingHeader::new("Comparison")
+            .id_source(id.with("compare entities"))
+            .default_open(true)
+            .show(ui, |ui| {
+                ui.columns(2, |columns| {
+                    for (column, &entity) in columns.iter_mut().zip(&entities) {
+                        column.label(self.entity_name(entity).as_ref());
+                    }
+                });
+
+                for (name, component_id, type_id) in &components {
+                    let reflect_component = type_registry
+                        .get(*type_id)
+                        .and_then(|registration| registration.data::<ReflectComponent>());
+
+                    CollapsingHeader::new(name)
+                        .id_source(id.with("compare entities").with(component_id))
+                        .show(ui, |ui| {
+                            let reflect_component = match reflect_component {
+                                Some(reflect_component) => reflect_component,
+                                None => {
+                                    ui.label("Not registered with #[reflect(Component)]");
+                                    return;
+                                }
+                            };
+
+                            ui.columns(2, |columns| {
+                                for (i, column) in columns.iter_mut().enumerate() {
+                                    changed |= self.compare_component_ui(
+                                        column,
+                                        reflect_component,
+                                        *component_id,
+                                        entities[i],
+                                        entities[1 - i],
+                                    );
+                                }
+                            });
+                        });
+                }
+            });
+        changed
+    }
+
+    /// Shows the component of `entity`, highlighting where it differs from the one of `other`.
+    fn compare_component_ui(
+        &self,
+        ui: &mut egui::Ui,
+        reflect_component: &ReflectComponent,
+        component_id: ComponentId,
+        entity: Entity,
+        other: Entity,
+    ) -> bool {
+        // SAFE: `entity` and `other` are different, so their components don't alias
+        let mut value = match unsafe {
+            reflect_component.reflect_component_unchecked_mut(self.world, entity)
+        } {
+            Some(value) => value,
+            None => {
+                ui.label("<missing>");
+                return false;
+            }
+        };
+
+        let world_ptr = self.world as *const _ as *mut _;
+        let context = unsafe {
+            Context::new_ptr(self.ui_ctx, world_ptr).with_id(component_id.index() as u64)
+        };
+        match reflect_component.reflect_component(self.world, other) {
+            Some(other) => crate::reflect::ui_for_reflect_compare(&mut *value, other, ui, &context),
+            None => crate::reflect::ui_for_reflect(&mut *value, ui, &context),
+        }
+    }
+
+    fn entity_ui(
+        &self,
+        ui: &mut egui::Ui,
+        entity: Entity,
+        params: &WorldInspectorParams,
+        id: egui::Id,
+        entity_options: &EntityAttributes,
+    ) -> bool {
+        self.entity_header_ui(ui, entity, params, id, entity_options)
+            .0
+    }
+
+    /// Like `entity_ui`, but also returns the rect of the entity's header.
+    fn entity_header_ui(
+        &self,
+        ui: &mut egui::Ui,
+        entity: Entity,
+        params: &WorldInspectorParams,
+        id: egui::Id,
+        entity_options: &EntityAttributes,
+    ) -> (bool, egui::Rect) {
+        let mut name = self.entity_name(entity).into_owned();
+        if params.selected.contains(&entity) {
+            name.insert_str(0, "✔ ");
+        }
+        if params.show_child_counts && self.world.get::<Children>(entity).is_some() {
+            let filter = self.world.get_resource::<WorldInspectorFilter>();
+            name.push_str(&format!(" ({})", self.descendant_count(entity, filter)));
         }
-    }
-}
-
-impl Default for WorldInspectorParams {
-    fn default() -> Self {
-        let mut params = WorldInspectorParams::empty();
 
-        params.ignore_components = [
-            TypeId::of::<Name>(),
-            TypeId::of::<Children>(),
-            TypeId::of::<Parent>(),
-            TypeId::of::<PreviousParent>(),
-            TypeId::of::<MainPass>(),
-            TypeId::of::<Draw>(),
-            TypeId::of::<RenderPipelines>(),
-        ]
-        .iter()
-        .copied()
-        .collect();
-        params.read_only_components = [TypeId::of::<GlobalTransform>()].iter().copied().collect();
+        let revealed = self
+            .world
+            .get_resource::<RevealedAncestors>()
+            .and_then(|revealed| revealed.0.get(&entity).copied());
+        let mut header_id = match revealed {
+            Some(count) => id.with(entity).with(count),
+            None => id.with(entity),
+        };
+        if let Some(collapse) = self.world.get_resource::<HierarchyCollapse>() {
+            header_id = header_id.with(collapse.counter);
+        }
 
-        params
-    }
-}
+        let truncated = truncated_header_label(ui, &name, params);
+        let response = CollapsingHeader::new(truncated.as_deref().unwrap_or(&name))
+            .id_source(header_id)
+            .default_open(revealed.is_some())
+            .show(ui, |ui| {
+                self.entity_ui_inner(ui, entity, params, id, entity_options)
+            });
+        if truncated.is_some() {
+            response.header_response.clone().on_hover_text(&name);
+        }
 
-struct WorldUIContext<'a> {
-    world: &'a mut World,
-    ui_ctx: Option<&'a egui::CtxRef>,
-    delete_entity: Cell<Option<Entity>>,
-}
-impl<'a> WorldUIContext<'a> {
-    fn new(ui_ctx: Option<&'a egui::CtxRef>, world: &'a mut World) -> WorldUIContext<'a> {
-        WorldUIContext {
-            world,
-            ui_ctx,
-            delete_entity: Cell::new(None),
+        if matches!(self.world.get_resource::<RevealTarget>(), Some(target) if target.0 == entity) {
+            response.header_response.scroll_to_me(egui::Align::Center);
+            self.revealed.set(true);
         }
-    }
-}
 
-impl Drop for WorldUIContext<'_> {
-    fn drop(&mut self) {
-        if let Some(entity) = self.delete_entity.get() {
-            despawn_with_children_recursive(self.world, entity);
+        if response.header_response.hovered() {
+            self.hovered.set(Some(entity));
         }
-    }
-}
 
-impl<'a> WorldUIContext<'a> {
-    fn entity_name(&self, entity: Entity) -> Cow<'_, str> {
-        match self.world.get_entity(entity) {
-            Some(entity) => guess_entity_name(entity),
-            None => format!("Entity {} (inexistent)", entity.id()).into(),
+        let modifiers = ui.input().modifiers;
+        if response.header_response.clicked() && (modifiers.command || modifiers.shift) {
+            self.toggle_selection.set(Some(entity));
         }
-    }
 
-    fn world_ui<F>(&mut self, ui: &mut egui::Ui, params: &WorldInspectorParams) -> bool
-    where
-        F: WorldQuery,
-        F::Fetch: FilterFetch,
-    {
-        let mut root_entities = self.world.query_filtered::<Entity, (Without<Parent>, F)>();
+        let mut changed = response.body_returned.unwrap_or(false);
+        changed |= self.entity_context_menu(ui, entity, params, id, &response.header_response);
 
-        // the entities are unique themselves, because only one WorldInspector can exist
-        let dummy_id = egui::Id::new(42);
-        let entity_options = params.entity_options();
+        if params.deep_search {
+            let search = self.world.get_resource::<DeepSearch>();
+            if matches!(search, Some(search) if search.is_match(entity)) {
+                let color = Color32::from_rgba_unmultiplied(0, 160, 255, 48);
+                ui.painter()
+                    .rect_filled(response.header_response.rect, 2.0, color);
+            }
+        }
 
-        let mut changed = false;
+        if let Some(watch) = self.world.get_resource::<ChangeWatch>() {
+            if !watch.watched.contains(&entity) && self.changed_since(entity, watch.since, params) {
+                let color = Color32::from_rgba_unmultiplied(255, 120, 0, 48);
+                ui.painter()
+                    .rect_filled(response.header_response.rect, 2.0, color);
+            }
+        }
 
-        for entity in root_entities.iter(self.world) {
-            changed |= self.entity_ui(ui, entity, params, dummy_id, &entity_options);
+        if let Some(ping) = self.world.get_resource::<EntityPing>() {
+            let elapsed = ui.input().time - ping.time;
+            if ping.entity == entity && elapsed < EntityPing::DURATION {
+                let fade = 1.0 - (elapsed / EntityPing::DURATION) as f32;
+                let color = Color32::from_rgba_unmultiplied(255, 255, 0, (fade * 96.0) as u8);
+                ui.painter()
+                    .rect_filled(response.header_response.rect, 2.0, color);
+                ui.ctx().request_repaint();
+            }
         }
 
-        changed
+        (changed, response.header_response.rect)
     }
 
-    fn entity_ui(
+    /// Popup menu with actions for `entity`, opened by right-clicking its header.
+    fn entity_context_menu(
         &self,
         ui: &mut egui::Ui,
         entity: Entity,
         params: &WorldInspectorParams,
         id: egui::Id,
-        entity_options: &EntityAttributes,
+        header_response: &egui::Response,
     ) -> bool {
-        CollapsingHeader::new(self.entity_name(entity))
-            .id_source(id.with(entity))
-            .show(ui, |ui| {
-                self.entity_ui_inner(ui, entity, params, id, entity_options)
+        let popup_id = id.with(entity).with("context menu");
+        if header_response.secondary_clicked() {
+            ui.memory().open_popup(popup_id);
+        }
+
+        let mut changed = false;
+        egui::popup_below_widget(ui, popup_id, header_response, |ui| {
+            let select = match params.selected.contains(&entity) {
+                true => "Deselect",
+                false => "Select",
+            };
+            if ui.button(select).clicked() {
+                self.toggle_selection.set(Some(entity));
+            }
+            if ui.button("Focus").clicked() {
+                self.jump_to.set(Some(entity));
+            }
+            if ui.button("Duplicate").clicked() {
+                self.duplicate_entity.set(Some(entity));
+                changed = true;
+            }
+            if ui.button("Rename…").clicked() {
+                self.rename_entity.set(Some(entity));
+            }
+            if ui.button("Copy path").clicked() {
+                ui.output().copied_text = self.entity_path(entity);
+            }
+            if params.despawnable_entities {
+                if ui.button("Despawn").clicked() {
+                    self.delete_entity.set(Some(entity));
+                    changed = true;
+                }
+            }
+        });
+        changed
+    }
+
+    /// How many descendants of `entity` match the `filter`.
+    fn descendant_count(&self, entity: Entity, filter: Option<&WorldInspectorFilter>) -> usize {
+        let children = match self.world.get::<Children>(entity) {
+            Some(children) => children,
+            None => return 0,
+        };
+        children
+            .iter()
+            .filter_map(|&child| self.world.get_entity(child))
+            .map(|child| {
+                let matches = match filter {
+                    Some(filter) => filter.matches(&child),
+                    None => true,
+                };
+                matches as usize + self.descendant_count(child.id(), filter)
             })
-            .body_returned
-            .unwrap_or(false)
+            .sum()
+    }
+
+    /// Popup menu with actions for a reflectable component, opened by right-clicking its header.
+    fn component_context_menu(
+        &self,
+        ui: &mut egui::Ui,
+        entity: Entity,
+        component_info: &ComponentInfo,
+        header_id: egui::Id,
+        header_response: &egui::Response,
+    ) {
+        let popup_id = header_id.with("context menu");
+        if header_response.secondary_clicked() {
+            ui.memory().open_popup(popup_id);
+        }
+
+        egui::popup_below_widget(ui, popup_id, header_response, |ui| {
+            if ui.button("Apply to all…").clicked() {
+                self.apply_to_all.set(Some((entity, component_info.id())));
+            }
+        });
+    }
+
+    /// The names of `entity` and its ancestors, separated by slashes.
+    fn entity_path(&self, entity: Entity) -> String {
+        let mut path = vec![self.entity_name(entity).into_owned()];
+        let mut current = entity;
+        while let Some(parent) = self.world.get::<Parent>(current) {
+            current = parent.0;
+            path.push(self.entity_name(current).into_owned());
+        }
+        path.reverse();
+        path.join("/")
     }
 
     fn entity_ui_inner(
@@ -189,32 +1635,69 @@ impl<'a> WorldUIContext<'a> {
 
         let mut changed = false;
 
-        changed |= self.component_kind_ui(
-            ui,
-            archetype.table_components(),
-            "Components",
-            entity,
-            entity_location,
-            params,
-            id,
-        );
-        changed |= self.component_kind_ui(
-            ui,
-            archetype.sparse_set_components(),
-            "Components (Sparse)",
-            entity,
-            entity_location,
-            params,
-            id,
-        );
+        if params.component_detail_view {
+            // a single list is easier to pick from than one per storage type
+            let components: Vec<ComponentId> = archetype
+                .table_components()
+                .iter()
+                .chain(archetype.sparse_set_components())
+                .copied()
+                .collect();
+            changed |= self.component_kind_ui(
+                ui,
+                &components,
+                "Components",
+                entity,
+                entity_location,
+                params,
+                id,
+            );
+        } else {
+            changed |= self.component_kind_ui(
+                ui,
+                archetype.table_components(),
+                "Components",
+                entity,
+                entity_location,
+                params,
+                id,
+            );
+            changed |= self.component_kind_ui(
+                ui,
+                archetype.sparse_set_components(),
+                "Components (Sparse)",
+                entity,
+                entity_location,
+                params,
+                id,
+            );
+        }
+
+        if params.show_sizes {
+            let total_size: usize = archetype
+                .table_components()
+                .iter()
+                .chain(archetype.sparse_set_components())
+                .filter_map(|&component_id| self.world.components().get_info(component_id))
+                .map(|component_info| component_info.layout().size())
+                .sum();
+            ui.label(format!("Total size: {}", format_size(total_size)));
+        }
 
         ui.separator();
 
         let children = self.world.get::<Children>(entity);
         if let Some(children) = children {
             ui.label("Children");
+            let mut child_headers = Vec::with_capacity(children.len());
             for &child in children.iter() {
-                self.entity_ui(ui, child, params, id, entity_options);
+                let (child_changed, header_rect) =
+                    self.entity_header_ui(ui, child, params, id, entity_options);
+                changed |= child_changed;
+                child_headers.push(header_rect);
+            }
+            if params.show_tree_lines {
+                tree_lines_ui(ui, &child_headers);
             }
         } else {
             ui.label("No children");
@@ -240,18 +1723,78 @@ impl<'a> WorldUIContext<'a> {
         params: &WorldInspectorParams,
         id: egui::Id,
     ) -> bool {
+        let components: Vec<ComponentId> = components
+            .iter()
+            .copied()
+            .filter(|&component_id| {
+                let component_info = self.world.components().get_info(component_id).unwrap();
+                !params.is_hidden_engine_component(component_info.name())
+            })
+            .filter(|&component_id| {
+                !params.editable_components_only || self.is_editable(component_id, params)
+            })
+            .collect();
+
         if !components.is_empty() {
             ui.label(title);
 
-            let iter = components.iter().map(|component_id| {
-                let component_info = self.world.components().get_info(*component_id).unwrap();
-                let name = pretty_type_name_str(component_info.name());
-                (name, component_info)
+            let short_names: Vec<String> = components
+                .iter()
+                .map(|&component_id| {
+                    let component_info = self.world.components().get_info(component_id).unwrap();
+                    pretty_type_name_str(component_info.name())
+                })
+                .collect();
+
+            let iter = components
+                .iter()
+                .zip(&short_names)
+                .map(|(component_id, short_name)| {
+                    let component_info = self.world.components().get_info(*component_id).unwrap();
+                    // types with the same name from different modules need their full path to be told apart
+                    let ambiguous = short_names
+                        .iter()
+                        .filter(|&name| name == short_name)
+                        .count()
+                        > 1;
+                    let mut name = match ambiguous {
+                        true => component_info.name().to_string(),
+                        false => short_name.clone(),
+                    };
+                    if params.show_sizes {
+                        name.push_str(&format!(
+                            " ({})",
+                            format_size(component_info.layout().size())
+                        ));
+                    }
+                    (name, component_info)
+                });
+            let sort = params.sort_components || !params.component_order.is_empty();
+            let iter = sort_iter_if(iter, sort, |a, b| {
+                let priority_a = params.component_priority(a.1.type_id());
+                let priority_b = params.component_priority(b.1.type_id());
+                priority_a.cmp(&priority_b).then_with(|| a.0.cmp(&b.0))
             });
-            let iter = sort_iter_if(iter, params.sort_components, |a, b| a.0.cmp(&b.0));
 
+            let mut markers = Vec::new();
+            let mut detail_items = Vec::new();
             let mut changed = false;
             for (name, component_info) in iter {
+                let ignored = matches!(component_info.type_id(), Some(type_id) if params.should_ignore_component(type_id));
+                if !params.show_markers && component_info.layout().size() == 0 {
+                    if !ignored {
+                        markers.push(pretty_type_name_str(component_info.name()));
+                    }
+                    continue;
+                }
+
+                if params.component_detail_view {
+                    if !ignored {
+                        detail_items.push((name, component_info));
+                    }
+                    continue;
+                }
+
                 changed |= self.component_ui(
                     ui,
                     name,
@@ -260,14 +1803,93 @@ impl<'a> WorldUIContext<'a> {
                     component_info,
                     params,
                     id,
+                    false,
+                    None,
+                );
+            }
+
+            if !detail_items.is_empty() {
+                changed |= self.component_detail_ui(
+                    ui,
+                    &detail_items,
+                    entity,
+                    entity_location,
+                    params,
+                    id,
                 );
             }
+
+            if !markers.is_empty() {
+                ui.label(format!("Markers: {}", markers.join(", ")));
+            }
+
             changed
         } else {
             false
         }
     }
 
+    /// Whether the component is neither read only nor missing an `Inspectable` or `#[reflect(Component)]` registration
+    fn is_editable(&self, component_id: ComponentId, params: &WorldInspectorParams) -> bool {
+        let type_id = match self
+            .world
+            .components()
+            .get_info(component_id)
+            .and_then(|info| info.type_id())
+        {
+            Some(type_id) if !params.is_read_only(type_id) => type_id,
+            _ => return false,
+        };
+
+        let inspectable_registry = self.world.get_resource::<InspectableRegistry>().unwrap();
+        let type_registry = self.world.get_resource::<TypeRegistryArc>().unwrap();
+        inspectable_registry.impls.contains_key(&type_id)
+            || snapshot::reflect_component(&type_registry.internal.read(), type_id).is_some()
+    }
+
+    /// The names of the `components` on the left and the widget of the selected one on the right,
+    /// for [`WorldInspectorParams::component_detail_view`].
+    fn component_detail_ui(
+        &self,
+        ui: &mut egui::Ui,
+        components: &[(String, &ComponentInfo)],
+        entity: Entity,
+        entity_location: EntityLocation,
+        params: &WorldInspectorParams,
+        id: egui::Id,
+    ) -> bool {
+        let selected = self
+            .world
+            .get_resource::<DetailSelection>()
+            .and_then(|selection| selection.0.get(&entity).copied());
+        let (name, component_info) = components
+            .iter()
+            .find(|(_, component_info)| Some(component_info.id()) == selected)
+            .unwrap_or(&components[0]);
+
+        let mut changed = false;
+        ui.columns(2, |columns| {
+            for (name, other) in components {
+                let is_selected = other.id() == component_info.id();
+                if columns[0].selectable_label(is_selected, name).clicked() && !is_selected {
+                    self.select_detail.set(Some((entity, other.id())));
+                }
+            }
+            changed = self.component_ui(
+                &mut columns[1],
+                name.clone(),
+                entity,
+                entity_location,
+                component_info,
+                params,
+                id,
+                true,
+                None,
+            );
+        });
+        changed
+    }
+
     fn component_ui(
         &self,
         ui: &mut egui::Ui,
@@ -277,6 +1899,8 @@ impl<'a> WorldUIContext<'a> {
         component_info: &ComponentInfo,
         params: &WorldInspectorParams,
         id: egui::Id,
+        headerless: bool,
+        mixed: Option<&HashSet<u64>>,
     ) -> bool {
         let type_id = match component_info.type_id() {
             Some(id) => id,
@@ -294,41 +1918,145 @@ impl<'a> WorldUIContext<'a> {
         let type_registry = self.world.get_resource::<TypeRegistryArc>().unwrap();
         let type_registry = &*type_registry.internal.read();
 
-        CollapsingHeader::new(name)
-            .id_source(id.with(component_info.id()))
-            .show(ui, |ui| {
-                if params.is_read_only(type_id) {
-                    ui.set_enabled(false);
-                }
+        let mut name = name;
+        let last_edit = self.world.get_resource::<LastEdit>();
+        if matches!(last_edit, Some(last_edit) if last_edit.entity == entity && last_edit.component == component_info.id())
+        {
+            name.push_str(" ✎");
+        }
 
-                let world_ptr = self.world as *const _ as *mut _;
-                let context = unsafe {
-                    Context::new_ptr(self.ui_ctx, world_ptr)
-                        .with_id(component_info.id().index() as u64)
-                };
+        let expansion = match params.expansion_by_type {
+            true => Some(
+                self.world
+                    .get_resource::<ComponentExpansion>()
+                    .and_then(|expansions| expansions.0.get(&type_id).copied())
+                    .unwrap_or((false, 0)),
+            ),
+            false => None,
+        };
+        let header_id = match expansion {
+            Some(expansion) => id.with(component_info.id()).with(expansion),
+            None => id.with(component_info.id()),
+        };
 
-                let result = unsafe {
-                    try_display(
-                        &self.world,
-                        entity,
-                        entity_location,
-                        component_info,
-                        type_id,
-                        inspectable_registry,
-                        type_registry,
-                        ui,
-                        &context,
-                    )
-                };
+        let body = |ui: &mut egui::Ui| {
+            if params.is_read_only(type_id) {
+                ui.set_enabled(false);
+            }
 
-                if result.is_err() {
-                    ui.label("Inspectable has not been defined for this component");
-                }
+            let world_ptr = self.world as *const _ as *mut _;
+            let mut context = unsafe {
+                Context::new_ptr(self.ui_ctx, world_ptr)
+                    .with_id(component_info.id().index() as u64)
+                    .with_commit_on_release(params.commit_on_release)
+                    .with_copy_buttons(params.show_copy_buttons)
+                    .with_max_depth(params.max_depth)
+            };
+            if let Some(mixed) = mixed {
+                context = context.with_mixed(mixed);
+            }
 
-                result.unwrap_or(false)
-            })
-            .body_returned
-            .unwrap_or(false)
+            let default = match params.diff_from_default {
+                true => inspectable_registry.defaults.get(&type_id),
+                false => None,
+            };
+            let result = match default {
+                Some(default) => display_diff_from_default(
+                    type_registry,
+                    type_id,
+                    &*default(),
+                    self.world,
+                    entity,
+                    ui,
+                    &context,
+                ),
+                None => Err(()),
+            };
+            let result = result.or_else(|_| unsafe {
+                try_display(
+                    &self.world,
+                    entity,
+                    entity_location,
+                    component_info,
+                    type_id,
+                    inspectable_registry,
+                    type_registry,
+                    ui,
+                    &context,
+                )
+            });
+
+            if result.is_err() {
+                ui.label("Inspectable has not been defined for this component");
+            }
+
+            result.unwrap_or(false)
+        };
+
+        let truncated = truncated_header_label(ui, &name, params);
+        let label = truncated.as_deref().unwrap_or(&name);
+        let storage_badge = match component_info.storage_type() {
+            StorageType::SparseSet if params.show_storage_badges => Some("sparse set"),
+            _ => None,
+        };
+        let annotation = match (
+            inspectable_registry.annotations.get(&type_id),
+            storage_badge,
+        ) {
+            (Some(annotation), Some(badge)) => {
+                Some(Cow::Owned(format!("{} · {}", annotation, badge)))
+            }
+            (Some(annotation), None) => Some(Cow::Borrowed(annotation.as_str())),
+            (None, badge) => badge.map(Cow::Borrowed),
+        };
+
+        if headerless || params.is_always_expanded(type_id) {
+            let label_response = ui.label(label);
+            if truncated.is_some() {
+                label_response.clone().on_hover_text(&name);
+            }
+            if let Some(annotation) = &annotation {
+                annotation_badge(ui, annotation, label_response.rect);
+            }
+            let changed = ui.indent(header_id, body).inner;
+            if changed {
+                self.last_edit.set(Some((entity, component_info.id())));
+            }
+            return changed;
+        }
+
+        let mut header = CollapsingHeader::new(label);
+        if let Some((open, _)) = expansion {
+            header = header.default_open(open);
+        }
+        let response = header.id_source(header_id).show(ui, body);
+        if truncated.is_some() {
+            response.header_response.clone().on_hover_text(&name);
+        }
+        if let Some(annotation) = &annotation {
+            annotation_badge(ui, annotation, response.header_response.rect);
+        }
+        if let (Some((open, counter)), true) = (expansion, response.header_response.clicked()) {
+            self.toggle_expansion
+                .set(Some((type_id, (!open, counter.wrapping_add(1)))));
+        }
+        if snapshot::reflect_component(type_registry, type_id).is_some()
+            && !snapshot::is_hierarchy_component(type_id)
+        {
+            self.component_context_menu(
+                ui,
+                entity,
+                component_info,
+                header_id,
+                &response.header_response,
+            );
+        }
+        let changed = response.body_returned.unwrap_or(false);
+
+        if changed {
+            self.last_edit.set(Some((entity, component_info.id())));
+        }
+        changed
     }
 }
 
@@ -426,6 +2154,34 @@ fn display_by_reflection(
     ))
 }
 
+/// Displays the fields of a reflected component which differ from `default`.
+fn display_diff_from_default(
+    type_registry: &TypeRegistryInternal,
+    type_id: TypeId,
+    default: &dyn Reflect,
+    world: &World,
+    entity: Entity,
+    ui: &mut egui::Ui,
+    context: &Context,
+) -> Result<bool, ()> {
+    let reflect_component = type_registry
+        .get(type_id)
+        .and_then(|registration| registration.data::<ReflectComponent>())
+        .ok_or(())?;
+
+    let mut reflected = unsafe {
+        reflect_component
+            .reflect_component_unchecked_mut(world, entity)
+            .ok_or(())?
+    };
+    Ok(crate::reflect::ui_for_reflect_diff(
+        &mut *reflected,
+        default,
+        ui,
+        context,
+    ))
+}
+
 // copied from bevy
 #[inline]
 unsafe fn get_component_and_ticks(
@@ -455,12 +2211,130 @@ unsafe fn get_component_and_ticks(
     }
 }
 
+/// Copies the component of `entity` onto all of the `others`.
+fn apply_to_entities(
+    world: &mut World,
+    reflect_component: &ReflectComponent,
+    entity: Entity,
+    others: &[Entity],
+) {
+    let value = match reflect_component.reflect_component(world, entity) {
+        Some(value) => value.clone_value(),
+        None => return,
+    };
+    for &other in others {
+        reflect_component.apply_component(world, other, &*value);
+    }
+}
+
+/// Opens the headers of the ancestors of `entity`, and scrolls to and highlights its row once it is drawn.
+fn reveal(world: &mut World, entity: Entity, time: f64) {
+    let mut ancestors = Vec::new();
+    let mut current = entity;
+    while let Some(parent) = world.get::<Parent>(current) {
+        current = parent.0;
+        ancestors.push(current);
+    }
+
+    let mut revealed = world.get_resource_or_insert_with(RevealedAncestors::default);
+    for ancestor in ancestors {
+        *revealed.0.entry(ancestor).or_insert(0) += 1;
+    }
+    world.insert_resource(RevealTarget(entity));
+    world.insert_resource(EntityPing { entity, time });
+}
+
 fn entity_is_bundle<B: Bundle>(e: &EntityRef) -> bool {
     B::type_info()
         .iter()
         .all(|type_info| e.contains_type_id(type_info.type_id()))
 }
 
+/// Draws a vertical line from the parent's expander triangle down to its last child,
+/// with a branch going to each child's expander triangle.
+fn tree_lines_ui(ui: &egui::Ui, child_headers: &[egui::Rect]) {
+    let (first, last) = match (child_headers.first(), child_headers.last()) {
+        (Some(first), Some(last)) => (first, last),
+        _ => return,
+    };
+
+    let indent = ui.spacing().indent;
+    let icon_width = ui.spacing().icon_width;
+    let stroke = ui.visuals().widgets.noninteractive.fg_stroke;
+    let painter = ui.painter();
+
+    // the children are laid out in the parent's body, which starts one indent to the right of the parent
+    let trunk_x = first.left() - indent / 2.0;
+    let top = painter.round_pos_to_pixels(egui::pos2(trunk_x, first.top()));
+    let bottom = painter.round_pos_to_pixels(egui::pos2(trunk_x, last.center().y));
+    painter.line_segment([top, bottom], stroke);
+
+    for header in child_headers {
+        let branch_end = header.left() + (indent - icon_width) / 2.0;
+        let start = painter.round_pos_to_pixels(egui::pos2(trunk_x, header.center().y));
+        let end = painter.round_pos_to_pixels(egui::pos2(branch_end, header.center().y));
+        painter.line_segment([start, end], stroke);
+    }
+}
+
+/// Paints `text` as a small badge at the right end of `rect`.
+fn annotation_badge(ui: &egui::Ui, text: &str, rect: egui::Rect) {
+    let galley = ui
+        .fonts()
+        .layout_no_wrap(egui::TextStyle::Small, text.to_string());
+    let padding = egui::vec2(4.0, 1.0);
+    let size = galley.size + 2.0 * padding;
+    let min = egui::pos2(
+        rect.right() - size.x - padding.x,
+        rect.center().y - size.y / 2.0,
+    );
+    let badge = egui::Rect::from_min_size(min, size);
+
+    let visuals = &ui.visuals().widgets.inactive;
+    ui.painter().rect_filled(badge, 3.0, visuals.bg_fill);
+    ui.painter()
+        .galley(badge.min + padding, galley, visuals.text_color());
+}
+
+/// If `params.truncate_labels` is set and `label` doesn't fit into a collapsing header
+/// in the remaining width, returns it shortened with an ellipsis.
+fn truncated_header_label(
+    ui: &egui::Ui,
+    label: &str,
+    params: &WorldInspectorParams,
+) -> Option<String> {
+    if !params.truncate_labels {
+        return None;
+    }
+
+    let max_width = ui.available_width() - ui.spacing().indent - ui.spacing().button_padding.x;
+    let width = |text: &str| {
+        ui.fonts()
+            .layout_no_wrap(egui::TextStyle::Button, text.to_string())
+            .size
+            .x
+    };
+    if width(label) <= max_width {
+        return None;
+    }
+
+    let mut truncated: String = label.to_string();
+    while truncated.pop().is_some() {
+        let candidate = format!("{}…", truncated);
+        if width(&candidate) <= max_width {
+            return Some(candidate);
+        }
+    }
+    Some("…".to_string())
+}
+
+fn format_size(size: usize) -> String {
+    match size {
+        1 => "1 byte".to_string(),
+        size => format!("{} bytes", size),
+    }
+}
+
 fn guess_entity_name(entity: EntityRef) -> Cow<'_, str> {
     if let Some(name) = entity.get::<Name>() {
         return name.as_str().into();
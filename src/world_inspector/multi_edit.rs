@@ -0,0 +1,174 @@
+use bevy::{
+    prelude::*,
+    reflect::{serde::ReflectSerializer, ReflectMut, ReflectRef, TypeRegistryInternal},
+    utils::HashSet,
+};
+
+use crate::Context;
+
+/// The ids which the fields of the component of `entity` differing from any of the `others` get in `context`,
+/// see [`Context::with_mixed`].
+/// If the component is compared as a whole, e.g. because it is a value type, it is mixed when the id of `context` is included.
+pub(crate) fn mixed_fields(
+    world: &World,
+    type_registry: &TypeRegistryInternal,
+    reflect_component: &ReflectComponent,
+    entity: Entity,
+    others: &[Entity],
+    context: &Context,
+) -> HashSet<u64> {
+    let mut mixed = HashSet::default();
+    let value = match reflect_component.reflect_component(world, entity) {
+        Some(value) => value,
+        None => return mixed,
+    };
+    for &other in others {
+        let other = match reflect_component.reflect_component(world, other) {
+            Some(other) => other,
+            None => continue,
+        };
+        diff_fields(
+            value,
+            other,
+            type_registry,
+            &mut Vec::new(),
+            &mut |path, leaf| {
+                // a struct with mixed fields isn't marked itself
+                if path.is_empty() && !leaf {
+                    return;
+                }
+                let mut field = None::<Context>;
+                for &i in path {
+                    field = Some(field.as_ref().unwrap_or(context).with_id(i as u64));
+                }
+                mixed.extend(field.as_ref().unwrap_or(context).id);
+            },
+        );
+    }
+    mixed
+}
+
+/// Applies the fields of the component of `entity` which were changed since `before` to all of the `others`,
+/// keeping the fields which weren't edited.
+/// Fields which can't be compared, because they implement neither `PartialEq` nor `Serialize` for reflection, are always applied.
+pub(crate) fn apply_changed_fields(
+    world: &mut World,
+    type_registry: &TypeRegistryInternal,
+    reflect_component: &ReflectComponent,
+    entity: Entity,
+    before: &dyn Reflect,
+    others: &[Entity],
+) {
+    let after = match reflect_component.reflect_component(world, entity) {
+        Some(value) => value.clone_value(),
+        None => return,
+    };
+    let mut changed = Vec::new();
+    diff_fields(
+        before,
+        &*after,
+        type_registry,
+        &mut Vec::new(),
+        &mut |path, leaf| {
+            if leaf {
+                changed.push(path.to_vec());
+            }
+        },
+    );
+    if changed.is_empty() {
+        return;
+    }
+
+    for &other in others {
+        let mut value = match reflect_component.reflect_component(world, other) {
+            Some(value) => value.clone_value(),
+            None => continue,
+        };
+        for path in &changed {
+            if let (Some(target), Some(source)) =
+                (field_at_mut(&mut *value, path), field_at(&*after, path))
+            {
+                target.apply(source);
+            }
+        }
+        reflect_component.apply_component(world, other, &*value);
+    }
+}
+
+/// Calls `differing` with the path of every field which differs between `a` and `b`, and whether it was compared as a whole.
+/// A path is made of the indices of the fields in their structs, tuples and lists, like the ids of their [`Context`]s.
+/// Returns whether `a` and `b` differ.
+fn diff_fields(
+    a: &dyn Reflect,
+    b: &dyn Reflect,
+    type_registry: &TypeRegistryInternal,
+    path: &mut Vec<usize>,
+    differing: &mut dyn FnMut(&[usize], bool),
+) -> bool {
+    let differs = match (fields(a), fields(b)) {
+        (Some(a), Some(b)) if a.len() == b.len() => {
+            let mut differs = false;
+            for (i, (a, b)) in a.into_iter().zip(b).enumerate() {
+                path.push(i);
+                differs |= diff_fields(a, b, type_registry, path, differing);
+                path.pop();
+            }
+            if differs {
+                differing(path, false);
+            }
+            return differs;
+        }
+        _ => !values_equal(a, b, type_registry),
+    };
+    if differs {
+        differing(path, true);
+    }
+    differs
+}
+
+/// The fields of structs, tuple structs and tuples, or the elements of a list.
+fn fields(value: &dyn Reflect) -> Option<Vec<&dyn Reflect>> {
+    let fields = match value.reflect_ref() {
+        ReflectRef::Struct(value) => value.iter_fields().collect(),
+        ReflectRef::TupleStruct(value) => value.iter_fields().collect(),
+        ReflectRef::Tuple(value) => value.iter_fields().collect(),
+        ReflectRef::List(value) => value.iter().collect(),
+        ReflectRef::Map(_) | ReflectRef::Value(_) => return None,
+    };
+    Some(fields)
+}
+
+fn field_at<'a>(value: &'a dyn Reflect, path: &[usize]) -> Option<&'a dyn Reflect> {
+    path.iter()
+        .try_fold(value, |value, &i| match value.reflect_ref() {
+            ReflectRef::Struct(value) => value.field_at(i),
+            ReflectRef::TupleStruct(value) => value.field(i),
+            ReflectRef::Tuple(value) => value.field(i),
+            ReflectRef::List(value) => value.get(i),
+            ReflectRef::Map(_) | ReflectRef::Value(_) => None,
+        })
+}
+
+fn field_at_mut<'a>(value: &'a mut dyn Reflect, path: &[usize]) -> Option<&'a mut dyn Reflect> {
+    path.iter()
+        .try_fold(value, |value, &i| match value.reflect_mut() {
+            ReflectMut::Struct(value) => value.field_at_mut(i),
+            ReflectMut::TupleStruct(value) => value.field_mut(i),
+            ReflectMut::Tuple(value) => value.field_mut(i),
+            ReflectMut::List(value) => value.get_mut(i),
+            ReflectMut::Map(_) | ReflectMut::Value(_) => None,
+        })
+}
+
+/// Compares using `PartialEq` if it is registered for reflection, otherwise by their serialized values.
+/// Values which can't be compared are considered different.
+fn values_equal(a: &dyn Reflect, b: &dyn Reflect, type_registry: &TypeRegistryInternal) -> bool {
+    if let Some(equal) = a.reflect_partial_eq(b) {
+        return equal;
+    }
+    let serialize = |value| ron::to_string(&ReflectSerializer::new(value, type_registry)).ok();
+    match (serialize(a), serialize(b)) {
+        (Some(a), Some(b)) => a == b,
+        _ => false,
+    }
+}
@@ -2,11 +2,18 @@ use std::marker::PhantomData;
 
 use bevy::{
     ecs::query::{FilterFetch, WorldQuery},
+    input::touch::Touches,
     prelude::*,
+    utils::HashMap,
+    window::WindowId,
 };
-use bevy_egui::{egui, EguiContext, EguiPlugin};
+use bevy_egui::{egui, EguiContext, EguiInput, EguiPlugin, EguiSettings, EguiSystem};
 
-use super::{WorldInspectorParams, WorldUIContext};
+use super::{
+    component_log::record_component_log, AutoSelectState, HierarchyCollapse, InspectorHovered,
+    PanelSide, PendingJump, WorldInspectorContext, WorldInspectorParams, WorldInspectorViews,
+    WorldUIContext,
+};
 use crate::InspectableRegistry;
 
 /// Plugin for displaying an inspector window of all entites in the world and their components.
@@ -99,9 +106,69 @@ where
         let world = app.world_mut();
         world.get_resource_or_insert_with(WorldInspectorParams::default);
         world.get_resource_or_insert_with(InspectableRegistry::default);
+        world.get_resource_or_insert_with(WorldInspectorViews::default);
+        world.get_resource_or_insert_with(AutoSelectState::default);
 
         app.add_system(world_inspector_ui::<F>.exclusive_system());
+        app.add_system_to_stage(
+            CoreStage::PostUpdate,
+            record_component_log.exclusive_system().at_end(),
+        );
+        app.add_system_to_stage(
+            CoreStage::PreUpdate,
+            touch_gestures
+                .system()
+                .after(EguiSystem::ProcessInput)
+                .before(EguiSystem::BeginFrame),
+        );
+    }
+}
+
+/// Turns dragging with two fingers into scrolling and pinching into changing the ui scale,
+/// if [`WorldInspectorParams::touch_gestures`] is set.
+///
+/// Bevy doesn't say which window was touched, so gestures only apply while the inspector's window is focused.
+/// The scale of `bevy_egui` is shared by all windows, so pinching scales the ui of the other windows as well.
+fn touch_gestures(
+    params: Res<WorldInspectorParams>,
+    touches: Option<Res<Touches>>,
+    windows: Res<Windows>,
+    mut egui_input: ResMut<HashMap<WindowId, EguiInput>>,
+    mut egui_settings: ResMut<EguiSettings>,
+) {
+    const MIN_SCALE: f64 = 0.5;
+    const MAX_SCALE: f64 = 3.0;
+
+    if !params.touch_gestures {
+        return;
+    }
+    let touches = match touches {
+        Some(touches) => touches,
+        None => return,
+    };
+    let input = match windows.get(params.window) {
+        Some(window) if window.is_focused() => match egui_input.get_mut(&params.window) {
+            Some(input) => input,
+            None => return,
+        },
+        _ => return,
+    };
+
+    let mut pressed = touches.iter();
+    let (a, b) = match (pressed.next(), pressed.next(), pressed.next()) {
+        (Some(a), Some(b), None) => (a, b),
+        _ => return,
+    };
+
+    let previous_distance = a.previous_position().distance(b.previous_position());
+    let distance = a.position().distance(b.position());
+    if previous_distance > 0.0 && distance > 0.0 {
+        let scale_factor = egui_settings.scale_factor * (distance / previous_distance) as f64;
+        egui_settings.scale_factor = scale_factor.clamp(MIN_SCALE, MAX_SCALE);
     }
+
+    let delta = (a.delta() + b.delta()) / 2.0 / egui_settings.scale_factor as f32;
+    input.raw_input.scroll_delta += egui::vec2(delta.x, delta.y);
 }
 
 fn world_inspector_ui<F>(world: &mut World)
@@ -111,32 +178,149 @@ where
 {
     let world_ptr = world as *mut _;
 
+    update_hierarchy_collapse(world);
+
     let params = world.get_resource::<WorldInspectorParams>().unwrap();
     if !params.enabled {
+        update_hovered(world, None);
         return;
     }
 
     let egui_context = world.get_resource::<EguiContext>().expect("EguiContext");
-    let ctx = match egui_context.try_ctx_for_window(params.window) {
-        Some(ctx) => ctx,
-        None => return,
+    let target = world.get_resource::<WorldInspectorContext>();
+    let ctx = match target.map(|target| &target.0) {
+        Some(ctx) => ctx.clone(),
+        None => match egui_context.try_ctx_for_window(params.window) {
+            Some(ctx) => ctx.clone(),
+            None => {
+                update_hovered(world, None);
+                return;
+            }
+        },
     };
 
     let mut is_open = true;
-    egui::Window::new("World")
-        .open(&mut is_open)
-        .scroll(true)
-        .show(ctx, |ui| {
-            crate::plugin::default_settings(ui);
-            let world: &mut World = unsafe { &mut *world_ptr };
-            let mut ui_context = WorldUIContext::new(Some(egui_context.ctx()), world);
-            ui_context.world_ui::<F>(ui, &params);
-        });
+    let mut toggle_selection = None;
+    let mut editable_only = None;
+    let mut jump_to = None;
+    let mut hovered = None;
+    let mut time = 0.0;
+    let add_contents = |ui: &mut egui::Ui| {
+        crate::plugin::default_settings(ui);
+        let world: &mut World = unsafe { &mut *world_ptr };
+        let mut ui_context = WorldUIContext::new(Some(&ctx), world);
+        ui_context.world_ui::<F>(ui, &params);
+        toggle_selection = ui_context.toggle_selection.get();
+        editable_only = ui_context.set_editable_only.get();
+        jump_to = ui_context.jump_to.get();
+        hovered = ui_context.hovered.get();
+        time = ui.input().time;
+    };
+    match params.panel_side {
+        None => {
+            egui::Window::new("World")
+                .open(&mut is_open)
+                .scroll(true)
+                .show(&ctx, add_contents);
+        }
+        Some(PanelSide::Left) => {
+            egui::SidePanel::left("world inspector panel", PanelSide::WIDTH).show(&ctx, |ui| {
+                egui::ScrollArea::auto_sized().show(ui, add_contents)
+            });
+        }
+        Some(PanelSide::Top) => {
+            egui::TopPanel::top("world inspector panel").show(&ctx, |ui| {
+                egui::ScrollArea::from_max_height(PanelSide::HEIGHT).show(ui, add_contents)
+            });
+        }
+    }
+
+    if is_open && params.continuous_repaint {
+        ctx.request_repaint();
+    }
+
+    let jump_to = jump_to.or_else(|| world.remove_resource::<PendingJump>().map(|jump| jump.0));
 
+    let mut params = world.get_resource_mut::<WorldInspectorParams>().unwrap();
     if !is_open {
-        world
-            .get_resource_mut::<WorldInspectorParams>()
-            .unwrap()
-            .enabled = false;
+        params.enabled = false;
+    }
+    if let Some(editable_only) = editable_only {
+        params.editable_components_only = editable_only;
+    }
+    if let Some(entity) = toggle_selection {
+        if !params.selected.remove(&entity) {
+            params.selected.insert(entity);
+        }
+    }
+    if let Some(entity) = jump_to {
+        params.selected.clear();
+        params.selected.insert(entity);
+    }
+
+    if params.auto_select_first {
+        auto_select_first::<F>(world);
+    }
+
+    if let Some(entity) = jump_to {
+        super::reveal(world, entity, time);
+    }
+
+    update_hovered(world, hovered);
+}
+
+/// Collapses the hierarchy when the inspector is opened with `collapse_on_open`.
+fn update_hierarchy_collapse(world: &mut World) {
+    let params = world.get_resource::<WorldInspectorParams>().unwrap();
+    let (enabled, collapse_on_open) = (params.enabled, params.collapse_on_open);
+
+    let mut collapse = world.get_resource_or_insert_with(HierarchyCollapse::default);
+    if enabled && !collapse.was_enabled && collapse_on_open {
+        collapse.counter = collapse.counter.wrapping_add(1);
+    }
+    collapse.was_enabled = enabled;
+}
+
+/// Moves the [`InspectorHovered`] marker to the `hovered` entity,
+/// removing it from every other entity even if its row is no longer shown.
+fn update_hovered(world: &mut World, hovered: Option<Entity>) {
+    let mut query = world.query_filtered::<Entity, With<InspectorHovered>>();
+    let previous: Vec<Entity> = query.iter(world).collect();
+    for entity in previous {
+        if Some(entity) != hovered {
+            world.entity_mut(entity).remove::<InspectorHovered>();
+        }
+    }
+
+    if let Some(mut entity) = hovered.and_then(|entity| world.get_entity_mut(entity)) {
+        if !entity.contains::<InspectorHovered>() {
+            entity.insert(InspectorHovered);
+        }
+    }
+}
+
+fn auto_select_first<F>(world: &mut World)
+where
+    F: WorldQuery,
+    F::Fetch: FilterFetch,
+{
+    let first = world
+        .query_filtered::<Entity, (Without<Parent>, F)>()
+        .iter(world)
+        .next();
+
+    let world = world.cell();
+    let mut params = world.get_resource_mut::<WorldInspectorParams>().unwrap();
+    let mut state = world.get_resource_mut::<AutoSelectState>().unwrap();
+
+    match first {
+        Some(entity) if !state.selected_once => {
+            if params.selected.is_empty() {
+                params.selected.insert(entity);
+            }
+            state.selected_once = true;
+        }
+        Some(_) => {}
+        None => state.selected_once = false,
     }
 }
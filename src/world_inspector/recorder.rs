@@ -0,0 +1,179 @@
+use bevy::{
+    prelude::*,
+    reflect::{
+        serde::{ReflectDeserializer, ReflectSerializer},
+        TypeRegistryArc, TypeRegistryInternal,
+    },
+    utils::HashMap,
+};
+use serde::de::DeserializeSeed;
+use std::{any::TypeId, path::Path};
+
+/// An edit of a component, stored as the RON serialized value of the whole component.
+/// The entity is identified by its `Name`, so that the edit can be replayed in another world.
+pub(crate) struct RecordedEdit {
+    entity: String,
+    component: String,
+    value: String,
+}
+
+/// The edits recorded by the "Record" button of the world inspector,
+/// see [`WorldInspectorParams::show_edit_recorder`](super::WorldInspectorParams::show_edit_recorder).
+#[derive(Default)]
+pub(crate) struct EditRecorder {
+    pub(crate) recording: bool,
+    pub(crate) edits: Vec<RecordedEdit>,
+    /// Why the last edit couldn't be recorded
+    pub(crate) error: Option<String>,
+}
+
+impl EditRecorder {
+    /// Records the current value of the component `type_id` of `entity`.
+    /// Consecutive edits of the same component, like the frames of a drag, are merged into one.
+    pub(crate) fn record(
+        &mut self,
+        world: &World,
+        entity: Entity,
+        type_id: TypeId,
+    ) -> Result<(), String> {
+        let name = world
+            .get::<Name>(entity)
+            .ok_or_else(|| "only entities with a `Name` can be recorded".to_string())?;
+
+        let type_registry = world.get_resource::<TypeRegistryArc>().unwrap().clone();
+        let type_registry = type_registry.internal.read();
+        let registration = type_registry.get(type_id);
+        let reflect_component =
+            registration.and_then(|registration| registration.data::<ReflectComponent>());
+        let (registration, reflect_component) = match (registration, reflect_component) {
+            (Some(registration), Some(reflect_component)) => (registration, reflect_component),
+            _ => {
+                return Err(
+                    "only components registered with `#[reflect(Component)]` can be recorded"
+                        .to_string(),
+                )
+            }
+        };
+
+        let value = reflect_component
+            .reflect_component(world, entity)
+            .ok_or_else(|| "the component no longer exists".to_string())?;
+        let value = ron::to_string(&ReflectSerializer::new(value, &type_registry))
+            .map_err(|error| error.to_string())?;
+
+        let edit = RecordedEdit {
+            entity: name.as_str().to_string(),
+            component: registration.name().to_string(),
+            value,
+        };
+        match self.edits.last_mut() {
+            Some(last) if last.entity == edit.entity && last.component == edit.component => {
+                *last = edit
+            }
+            _ => self.edits.push(edit),
+        }
+        Ok(())
+    }
+
+    /// Writes the recorded edits to `path` as a RON list of `(entity, component, value)` tuples.
+    pub(crate) fn save(&self, path: &Path) -> Result<(), String> {
+        let edits: Vec<(&str, &str, &str)> = self
+            .edits
+            .iter()
+            .map(|edit| {
+                (
+                    edit.entity.as_str(),
+                    edit.component.as_str(),
+                    edit.value.as_str(),
+                )
+            })
+            .collect();
+        let script = ron::ser::to_string_pretty(&edits, ron::ser::PrettyConfig::default())
+            .map_err(|error| error.to_string())?;
+        std::fs::write(path, script).map_err(|error| error.to_string())
+    }
+
+    /// Replaces the recorded edits with the ones saved at `path`.
+    pub(crate) fn load(&mut self, path: &Path) -> Result<(), String> {
+        let script = std::fs::read_to_string(path).map_err(|error| error.to_string())?;
+        let edits: Vec<(String, String, String)> =
+            ron::from_str(&script).map_err(|error| error.to_string())?;
+        self.edits = edits
+            .into_iter()
+            .map(|(entity, component, value)| RecordedEdit {
+                entity,
+                component,
+                value,
+            })
+            .collect();
+        Ok(())
+    }
+
+    /// Applies the recorded edits in order, inserting components which are missing.
+    /// Edits whose entity or component type can't be found are skipped with a warning.
+    /// Returns how many edits were applied.
+    pub(crate) fn replay(&self, world: &mut World) -> usize {
+        let type_registry = world.get_resource::<TypeRegistryArc>().unwrap().clone();
+        let type_registry = type_registry.internal.read();
+
+        let mut entities = HashMap::default();
+        for (entity, name) in world.query::<(Entity, &Name)>().iter(world) {
+            entities.entry(name.as_str().to_string()).or_insert(entity);
+        }
+
+        let mut applied = 0;
+        for edit in &self.edits {
+            let entity = match entities.get(&edit.entity) {
+                Some(&entity) => entity,
+                None => {
+                    warn!(
+                        "skipping edit of `{}`, no entity has this name",
+                        edit.entity
+                    );
+                    continue;
+                }
+            };
+            let registration = type_registry.get_with_name(&edit.component);
+            let reflect_component =
+                registration.and_then(|registration| registration.data::<ReflectComponent>());
+            let (type_id, reflect_component) = match (registration, reflect_component) {
+                (Some(registration), Some(reflect_component)) => {
+                    (registration.type_id(), reflect_component)
+                }
+                _ => {
+                    warn!(
+                        "skipping edit of `{}`, it is not registered with `#[reflect(Component)]`",
+                        edit.component
+                    );
+                    continue;
+                }
+            };
+            let value = match deserialize(&type_registry, &edit.value) {
+                Ok(value) => value,
+                Err(error) => {
+                    warn!("skipping edit of `{}`: {}", edit.component, error);
+                    continue;
+                }
+            };
+
+            if world.entity(entity).contains_type_id(type_id) {
+                reflect_component.apply_component(world, entity, &*value);
+            } else {
+                reflect_component.add_component(world, entity, &*value);
+            }
+            applied += 1;
+        }
+        applied
+    }
+}
+
+fn deserialize(
+    type_registry: &TypeRegistryInternal,
+    value: &str,
+) -> Result<Box<dyn Reflect>, String> {
+    let mut deserializer =
+        ron::de::Deserializer::from_str(value).map_err(|error| error.to_string())?;
+    ReflectDeserializer::new(type_registry)
+        .deserialize(&mut deserializer)
+        .map_err(|error| error.to_string())
+}
@@ -0,0 +1,103 @@
+use bevy::{
+    prelude::*,
+    reflect::{ReflectRef, TypeRegistryArc},
+    utils::HashSet,
+};
+
+use std::borrow::Cow;
+
+use super::{snapshot::for_each_reflected_component, WorldInspectorParams};
+use crate::reflect::visit_reflect;
+
+/// Searches the values of the reflectable components of all entities for a string,
+/// see [`WorldInspectorParams::deep_search`].
+/// Components which are only registered in the [`InspectableRegistry`](crate::InspectableRegistry) can't be turned into text, so they aren't searched.
+/// This is expensive, so the matching entities are only recomputed when the query changes or on [`DeepSearch::refresh`].
+#[derive(Default)]
+pub(crate) struct DeepSearch {
+    pub(crate) query: String,
+    searched: Option<String>,
+    matches: HashSet<Entity>,
+}
+
+impl DeepSearch {
+    pub(crate) fn update(&mut self, world: &World, params: &WorldInspectorParams) {
+        if self.searched.as_ref() == Some(&self.query) {
+            return;
+        }
+        self.searched = Some(self.query.clone());
+
+        self.matches.clear();
+        if self.query.is_empty() {
+            return;
+        }
+
+        let type_registry = world.get_resource::<TypeRegistryArc>().unwrap().clone();
+        let type_registry = type_registry.internal.read();
+        let query = self.query.to_lowercase();
+
+        let matches = &mut self.matches;
+        for_each_reflected_component(
+            world,
+            &type_registry,
+            |type_id| !params.should_ignore_component(type_id),
+            |entity, _, value| {
+                if !matches.contains(&entity) && reflect_contains(value, &query) {
+                    matches.insert(entity);
+                }
+            },
+        );
+    }
+
+    /// Searches again on the next update, e.g. because the values of the components changed.
+    pub(crate) fn refresh(&mut self) {
+        self.searched = None;
+    }
+
+    pub(crate) fn is_match(&self, entity: Entity) -> bool {
+        self.matches.contains(&entity)
+    }
+
+    pub(crate) fn len(&self) -> usize {
+        self.matches.len()
+    }
+}
+
+/// Whether any of the values nested in `value` contains the lowercase `query`.
+fn reflect_contains(value: &dyn Reflect, query: &str) -> bool {
+    let mut contains = false;
+    visit_reflect(value, &mut |value| {
+        if contains || !matches!(value.reflect_ref(), ReflectRef::Value(_)) {
+            return;
+        }
+        contains =
+            matches!(value_to_string(value), Some(string) if string.to_lowercase().contains(query));
+    });
+    contains
+}
+
+macro_rules! value_to_string {
+    ($value:ident: $($ty:ty),* $(,)?) => {
+        $(if let Some(value) = $value.downcast_ref::<$ty>() {
+            return Some(format!("{:?}", value));
+        })*
+    };
+}
+
+/// The string representation of common types reflected as opaque values.
+fn value_to_string(value: &dyn Reflect) -> Option<String> {
+    if let Some(value) = value.downcast_ref::<String>() {
+        return Some(value.clone());
+    }
+    if let Some(value) = value.downcast_ref::<Cow<'static, str>>() {
+        return Some(value.to_string());
+    }
+    if let Some(value) = value.downcast_ref::<f32>() {
+        return Some(value.to_string());
+    }
+    if let Some(value) = value.downcast_ref::<f64>() {
+        return Some(value.to_string());
+    }
+    value_to_string!(value: bool, u8, u16, u32, u64, usize, i8, i16, i32, i64, isize, Vec2, Vec3, Vec4, Quat, Color);
+    None
+}
@@ -0,0 +1,149 @@
+use bevy::{
+    ecs::{archetype::Archetype, component::ComponentId},
+    prelude::*,
+    reflect::{TypeRegistryArc, TypeRegistryInternal},
+};
+use std::any::TypeId;
+
+use super::WorldInspectorParams;
+
+/// In-memory copy of the reflectable components of all entities,
+/// taken by the "Snapshot" button of the world inspector.
+#[derive(Default)]
+pub(crate) struct WorldSnapshot {
+    components: Vec<(Entity, TypeId, Box<dyn Reflect>)>,
+}
+
+impl WorldSnapshot {
+    /// Copies every component registered with `#[reflect(Component)]`, except for ignored ones.
+    pub(crate) fn take(world: &World, params: &WorldInspectorParams) -> Self {
+        let type_registry = world.get_resource::<TypeRegistryArc>().unwrap().clone();
+        let type_registry = type_registry.internal.read();
+
+        let mut components = Vec::new();
+        for_each_reflected_component(
+            world,
+            &type_registry,
+            |type_id| !params.should_ignore_component(type_id) && !is_hierarchy_component(type_id),
+            |entity, type_id, value| components.push((entity, type_id, value.clone_value())),
+        );
+
+        WorldSnapshot { components }
+    }
+
+    /// Writes the copied components back. Entities which were despawned since are skipped.
+    pub(crate) fn restore(&self, world: &mut World) {
+        let type_registry = world.get_resource::<TypeRegistryArc>().unwrap().clone();
+        let type_registry = type_registry.internal.read();
+
+        for (entity, type_id, value) in &self.components {
+            let entity_ref = match world.get_entity(*entity) {
+                Some(entity_ref) => entity_ref,
+                None => continue,
+            };
+            let reflect_component = match reflect_component(&type_registry, *type_id) {
+                Some(reflect_component) => reflect_component,
+                None => continue,
+            };
+
+            if entity_ref.contains_type_id(*type_id) {
+                reflect_component.apply_component(world, *entity, &**value);
+            } else {
+                reflect_component.add_component(world, *entity, &**value);
+            }
+        }
+    }
+
+    pub(crate) fn len(&self) -> usize {
+        self.components.len()
+    }
+}
+
+/// Spawns a copy of `entity` with all of its components registered with `#[reflect(Component)]`,
+/// as a sibling of it. Its children aren't copied.
+pub(crate) fn duplicate(world: &mut World, entity: Entity) -> Entity {
+    let type_registry = world.get_resource::<TypeRegistryArc>().unwrap().clone();
+    let type_registry = type_registry.internal.read();
+
+    let entity_ref = world.entity(entity);
+    let components: Vec<(&ReflectComponent, Box<dyn Reflect>)> = entity_ref
+        .archetype()
+        .components()
+        .filter_map(|component_id| world.components().get_info(component_id)?.type_id())
+        .filter(|&type_id| !is_hierarchy_component(type_id))
+        .filter_map(|type_id| {
+            let reflect_component = reflect_component(&type_registry, type_id)?;
+            let value = reflect_component.reflect_component(world, entity)?;
+            Some((reflect_component, value.clone_value()))
+        })
+        .collect();
+    let parent = world.get::<Parent>(entity).map(|parent| parent.0);
+
+    let copy = world.spawn().id();
+    for (reflect_component, value) in components {
+        reflect_component.add_component(world, copy, &*value);
+    }
+    if let Some(parent) = parent {
+        world.entity_mut(parent).push_children(&[copy]);
+    }
+    copy
+}
+
+/// Whether `type_id` is `Parent`, `PreviousParent` or `Children`, which have to be kept consistent with each other,
+/// so they aren't copied or restored on their own.
+pub(super) fn is_hierarchy_component(type_id: TypeId) -> bool {
+    type_id == TypeId::of::<Parent>()
+        || type_id == TypeId::of::<PreviousParent>()
+        || type_id == TypeId::of::<Children>()
+}
+
+/// Calls `f` with every archetype and each of its components which has a type id.
+pub(super) fn for_each_archetype_component(
+    world: &World,
+    mut f: impl FnMut(&Archetype, ComponentId, TypeId),
+) {
+    for archetype in world.archetypes().iter() {
+        for component_id in archetype.components() {
+            let type_id = world
+                .components()
+                .get_info(component_id)
+                .and_then(|info| info.type_id());
+            if let Some(type_id) = type_id {
+                f(archetype, component_id, type_id);
+            }
+        }
+    }
+}
+
+/// Calls `f` with every entity and the value of each of its components registered with `#[reflect(Component)]`,
+/// except for the component types `include` returns `false` for.
+pub(crate) fn for_each_reflected_component(
+    world: &World,
+    type_registry: &TypeRegistryInternal,
+    mut include: impl FnMut(TypeId) -> bool,
+    mut f: impl FnMut(Entity, TypeId, &dyn Reflect),
+) {
+    for_each_archetype_component(world, |archetype, _, type_id| {
+        if !include(type_id) {
+            return;
+        }
+        let reflect_component = match reflect_component(type_registry, type_id) {
+            Some(reflect_component) => reflect_component,
+            None => return,
+        };
+        for &entity in archetype.entities() {
+            if let Some(value) = reflect_component.reflect_component(world, entity) {
+                f(entity, type_id, value);
+            }
+        }
+    });
+}
+
+pub(super) fn reflect_component(
+    type_registry: &TypeRegistryInternal,
+    type_id: TypeId,
+) -> Option<&ReflectComponent> {
+    type_registry
+        .get(type_id)
+        .and_then(|registration| registration.data::<ReflectComponent>())
+}
@@ -0,0 +1,93 @@
+use bevy::{
+    ecs::{component::Component, world::EntityRef},
+    prelude::*,
+};
+
+pub(crate) type ViewCallback = Box<dyn Fn(&mut World) -> Vec<Entity> + Send + Sync>;
+pub(crate) type GroupingCallback = Box<dyn Fn(&EntityRef) -> Option<String> + Send + Sync>;
+
+/// The `WorldInspectorViews` can be used to add named views to the [`WorldInspectorPlugin`](crate::WorldInspectorPlugin).
+///
+/// Every view is shown as a collapsible section above the entity hierarchy,
+/// listing the entities returned by its callback.
+/// The callbacks are run every frame the inspector is shown.
+/// They get mutable access to the world because bevy's queries require it,
+/// but they shouldn't modify it.
+/// ```rust,no_run
+/// # use bevy::prelude::*;
+/// # use bevy_inspector_egui::WorldInspectorViews;
+/// struct Health(f32);
+///
+/// fn setup(mut views: ResMut<WorldInspectorViews>) {
+///     views.add_view("Low health", |world| {
+///         world
+///             .query::<(Entity, &Health)>()
+///             .iter(world)
+///             .filter(|(_, health)| health.0 < 10.0)
+///             .map(|(entity, _)| entity)
+///             .collect()
+///     });
+/// }
+/// ```
+///
+/// Groupings can be added as well, which can be picked in the world inspector to
+/// bucket the entities by the value of one of their components:
+/// ```rust,no_run
+/// # use bevy::prelude::*;
+/// # use bevy_inspector_egui::WorldInspectorViews;
+/// #[derive(Debug)]
+/// enum Team {
+///     Red,
+///     Blue,
+/// }
+///
+/// fn setup(mut views: ResMut<WorldInspectorViews>) {
+///     views.add_grouping("Team", |team: &Team| format!("{:?}", team));
+/// }
+/// ```
+#[derive(Default)]
+pub struct WorldInspectorViews {
+    pub(crate) views: Vec<(String, ViewCallback)>,
+    pub(crate) groupings: Vec<(String, GroupingCallback)>,
+    pub(crate) active_grouping: Option<usize>,
+}
+
+impl WorldInspectorViews {
+    /// Add a view called `name`, which shows the entities returned by `view`.
+    pub fn add_view<F>(&mut self, name: impl Into<String>, view: F)
+    where
+        F: Fn(&mut World) -> Vec<Entity> + Send + Sync + 'static,
+    {
+        self.views.push((name.into(), Box::new(view)));
+    }
+
+    /// Add a grouping called `name`, which groups the entities by the string `key` returns for their `T` component.
+    /// Entities without a `T` are put into an "(ungrouped)" group.
+    pub fn add_grouping<T, F>(&mut self, name: impl Into<String>, key: F)
+    where
+        T: Component,
+        F: Fn(&T) -> String + Send + Sync + 'static,
+    {
+        let grouping = move |entity: &EntityRef| entity.get::<T>().map(&key);
+        self.groupings.push((name.into(), Box::new(grouping)));
+    }
+
+    /// Variant of [`WorldInspectorViews::add_view`] which returns self by-value.
+    pub fn with_view<F>(mut self, name: impl Into<String>, view: F) -> Self
+    where
+        F: Fn(&mut World) -> Vec<Entity> + Send + Sync + 'static,
+    {
+        self.add_view(name, view);
+        self
+    }
+
+    /// Variant of [`WorldInspectorViews::add_grouping`] which returns self by-value.
+    pub fn with_grouping<T, F>(mut self, name: impl Into<String>, key: F) -> Self
+    where
+        T: Component,
+        F: Fn(&T) -> String + Send + Sync + 'static,
+    {
+        self.add_grouping(name, key);
+        self
+    }
+}
@@ -8,3 +8,34 @@ struct Struct {
     #[inspectable(alpha = true)]
     color: Color,
 }
+
+#[derive(Inspectable, Default)]
+struct Inner {
+    a: f32,
+    b: bool,
+}
+
+#[derive(Inspectable)]
+struct Outer {
+    #[inspectable(collapse)]
+    collapse: Inner,
+    #[inspectable(collapsed, label = "Collapsed")]
+    collapsed: Inner,
+}
+
+#[derive(Inspectable)]
+struct Tooltips {
+    #[inspectable(tooltip = "Health regenerates over time")]
+    health: f32,
+    #[inspectable(label = "Max health", tooltip = "Upper limit for health")]
+    max_health: f32,
+}
+
+#[derive(Inspectable)]
+enum TooltipEnum {
+    Variant {
+        #[inspectable(tooltip = "first field")]
+        a: f32,
+        b: f32,
+    },
+}
@@ -0,0 +1,21 @@
+use bevy_inspector_egui::Inspectable;
+
+#[derive(Inspectable)]
+#[inspectable(button = "respawn", button = reset)]
+struct Player {
+    health: f32,
+    #[inspectable(button = "heal")]
+    heal: (),
+}
+
+impl Player {
+    fn respawn(&mut self) {
+        self.health = 100.0;
+    }
+    fn reset(&mut self) {
+        self.health = 0.0;
+    }
+    fn heal(&mut self) {
+        self.health += 10.0;
+    }
+}
@@ -0,0 +1,10 @@
+use bevy::utils::{HashMap, HashSet};
+use bevy_inspector_egui::Inspectable;
+
+#[derive(Inspectable)]
+struct Collections {
+    list: Vec<f32>,
+    array: [u8; 3],
+    set: HashSet<u32>,
+    map: HashMap<String, f32>,
+}
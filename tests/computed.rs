@@ -0,0 +1,17 @@
+use bevy::math::Vec3;
+use bevy_inspector_egui::Inspectable;
+
+#[derive(Inspectable)]
+#[inspectable(computed = "speed", computed = is_moving)]
+struct Velocity {
+    linear: Vec3,
+}
+
+impl Velocity {
+    fn speed(&self) -> f32 {
+        self.linear.length()
+    }
+    fn is_moving(&self) -> bool {
+        self.linear != Vec3::ZERO
+    }
+}
@@ -1,4 +1,4 @@
-use bevy::math::prelude::*;
+use bevy::math::{prelude::*, Vec3A};
 use bevy_inspector_egui::Inspectable;
 
 #[derive(Inspectable)]
@@ -19,4 +19,22 @@ struct IntegerAttribues {
     #[inspectable(min = Vec2::new(0.1, 0.2))]
     k: Vec2,
     l: Vec3,
+    #[inspectable(angle)]
+    m: f32,
+    #[inspectable(angle, min = 0.0, max = 90.0)]
+    n: f64,
+    #[inspectable(hex)]
+    o: u8,
+    #[inspectable(ascii)]
+    p: u8,
+    #[inspectable(direction)]
+    q: Vec3,
+    #[inspectable(direction, normalize_on_edit)]
+    r: Vec3,
+    #[inspectable(graph)]
+    s: f32,
+    #[inspectable(min = Vec3A::ZERO, direction)]
+    t: Vec3A,
+    #[inspectable(hex)]
+    u: u64,
 }
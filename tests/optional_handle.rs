@@ -0,0 +1,10 @@
+#![allow(dead_code)]
+use bevy::prelude::*;
+use bevy_inspector_egui::Inspectable;
+
+#[derive(Inspectable)]
+struct Data {
+    #[inspectable(replacement = Handle::default as fn() -> _)]
+    material: Option<Handle<ColorMaterial>>,
+    mesh: Option<Handle<Mesh>>,
+}
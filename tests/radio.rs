@@ -0,0 +1,20 @@
+use bevy_inspector_egui::Inspectable;
+
+#[derive(Inspectable)]
+#[inspectable(radio)]
+enum Mode {
+    Off,
+    On,
+    Custom { intensity: f32 },
+}
+
+#[derive(Inspectable)]
+#[inspectable(radio, variant_label = "label")]
+enum Quality {
+    LowQuality,
+    HighQuality,
+}
+
+fn label(variant: &str) -> String {
+    variant.to_lowercase()
+}
@@ -0,0 +1,13 @@
+use bevy::prelude::*;
+use bevy_inspector_egui::Inspectable;
+
+#[derive(Inspectable, Reflect, Default)]
+#[reflect(Component)]
+struct Component {
+    #[reflect(ignore)]
+    cache: Vec<f32>,
+    speed: f32,
+}
+
+#[derive(Inspectable, Reflect, Default)]
+struct TupleStruct(#[reflect(ignore)] u8, f32);
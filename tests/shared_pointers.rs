@@ -0,0 +1,13 @@
+use bevy_inspector_egui::Inspectable;
+use std::{
+    cell::RefCell,
+    rc::Rc,
+    sync::{Arc, Mutex},
+};
+
+#[derive(Inspectable)]
+struct Shared {
+    #[inspectable(min = 1.0)]
+    arc: Arc<Mutex<f32>>,
+    rc: Rc<RefCell<String>>,
+}
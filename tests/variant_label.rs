@@ -0,0 +1,17 @@
+use bevy_inspector_egui::Inspectable;
+
+fn label(variant: &str) -> String {
+    match variant {
+        "LowQuality" => "Low Quality".to_string(),
+        "HighQuality" => "High Quality".to_string(),
+        other => other.to_string(),
+    }
+}
+
+#[derive(Inspectable)]
+#[inspectable(variant_label = "label")]
+enum Quality {
+    LowQuality,
+    HighQuality,
+    Custom { samples: u32 },
+}
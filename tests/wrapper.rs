@@ -0,0 +1,18 @@
+use bevy_inspector_egui::Inspectable;
+
+#[derive(Inspectable)]
+#[inspectable(wrapper)]
+struct Health(#[inspectable(min = 0.0, max = 100.0)] f32);
+
+#[derive(Inspectable)]
+#[inspectable(wrapper)]
+struct Speed {
+    #[inspectable(collapse)]
+    value: f32,
+}
+
+#[derive(Inspectable)]
+struct Player {
+    health: Health,
+    speed: Speed,
+}